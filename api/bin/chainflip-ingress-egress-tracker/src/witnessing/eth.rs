@@ -54,6 +54,7 @@ where
 			"eth_subscribe",
 			"Ethereum",
 			Ethereum::WITNESS_PERIOD,
+			Default::default(),
 		)?
 	};
 
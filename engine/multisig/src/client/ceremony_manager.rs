@@ -9,6 +9,7 @@ use std::{
 	fmt::{Debug, Display},
 	marker::PhantomData,
 	sync::Arc,
+	time::Duration,
 };
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use tracing::{debug, info_span, trace, warn, Instrument};
@@ -26,7 +27,7 @@ use crate::{
 	ChainSigning,
 };
 use cf_primitives::{AuthorityCount, CeremonyId};
-use state_chain_runtime::AccountId;
+use state_chain_runtime::{constants::common::MAX_STAGE_DURATION_SECONDS, AccountId};
 use utilities::{
 	metrics::{AUTHORIZED_CEREMONIES, CEREMONY_BAD_MSG, UNAUTHORIZED_CEREMONIES},
 	task_scope::{task_scope, Scope, ScopedJoinHandle},
@@ -55,6 +56,26 @@ pub type CeremonyOutcome<C> = Result<
 	(BTreeSet<AccountId>, <C as CeremonyTrait>::FailureReason),
 >;
 
+/// The time each ceremony stage is given to complete unless configured otherwise. This must be
+/// kept consistent with the ceremony timeouts used by the State Chain.
+pub const DEFAULT_STAGE_DURATION: Duration = Duration::from_secs(MAX_STAGE_DURATION_SECONDS as u64);
+
+/// How long each stage of a ceremony is given to complete before the ceremony times out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CeremonyTimeouts {
+	pub keygen_stage_duration: Duration,
+	pub signing_stage_duration: Duration,
+}
+
+impl Default for CeremonyTimeouts {
+	fn default() -> Self {
+		Self {
+			keygen_stage_duration: DEFAULT_STAGE_DURATION,
+			signing_stage_duration: DEFAULT_STAGE_DURATION,
+		}
+	}
+}
+
 pub type CeremonyResultSender<Ceremony> = oneshot::Sender<CeremonyOutcome<Ceremony>>;
 pub type CeremonyResultReceiver<Ceremony> = oneshot::Receiver<CeremonyOutcome<Ceremony>>;
 
@@ -337,12 +358,13 @@ impl<Chain: ChainSigning> CeremonyManager<Chain> {
 		my_account_id: AccountId,
 		outgoing_p2p_message_sender: UnboundedSender<OutgoingMultisigStageMessages>,
 		latest_ceremony_id: CeremonyId,
+		timeouts: CeremonyTimeouts,
 	) -> Self {
 		CeremonyManager {
 			my_account_id,
 			outgoing_p2p_message_sender,
-			signing_states: CeremonyStates::new(),
-			keygen_states: CeremonyStates::new(),
+			signing_states: CeremonyStates::new(timeouts.signing_stage_duration),
+			keygen_states: CeremonyStates::new(timeouts.keygen_stage_duration),
 			latest_ceremony_id,
 		}
 	}
@@ -702,12 +724,14 @@ struct CeremonyStates<Ceremony: CeremonyTrait> {
 	outcome_sender: UnboundedSender<(CeremonyId, CeremonyOutcome<Ceremony>)>,
 	/// All authorised ceremonies will send their outcome here
 	outcome_receiver: UnboundedReceiver<(CeremonyId, CeremonyOutcome<Ceremony>)>,
+	// The time each stage of these ceremonies is given to complete
+	stage_duration: Duration,
 }
 
 impl<Ceremony: CeremonyTrait> CeremonyStates<Ceremony> {
-	fn new() -> Self {
+	fn new(stage_duration: Duration) -> Self {
 		let (outcome_sender, outcome_receiver) = mpsc::unbounded_channel();
-		Self { ceremony_handles: HashMap::new(), outcome_sender, outcome_receiver }
+		Self { ceremony_handles: HashMap::new(), outcome_sender, outcome_receiver, stage_duration }
 	}
 
 	/// Process ceremony data arriving from a peer,
@@ -742,6 +766,7 @@ impl<Ceremony: CeremonyTrait> CeremonyStates<Ceremony> {
 				e.insert(CeremonyHandle::spawn::<Chain>(
 					ceremony_id,
 					self.outcome_sender.clone(),
+					self.stage_duration,
 					scope,
 				));
 				let total = self.count_unauthorised_ceremonies();
@@ -772,7 +797,12 @@ impl<Ceremony: CeremonyTrait> CeremonyStates<Ceremony> {
 		Chain: ChainSigning<CryptoScheme = Ceremony::Crypto>,
 	{
 		self.ceremony_handles.entry(ceremony_id).or_insert_with(|| {
-			CeremonyHandle::spawn::<Chain>(ceremony_id, self.outcome_sender.clone(), scope)
+			CeremonyHandle::spawn::<Chain>(
+				ceremony_id,
+				self.outcome_sender.clone(),
+				self.stage_duration,
+				scope,
+			)
 		})
 	}
 
@@ -847,6 +877,7 @@ impl<Ceremony: CeremonyTrait> CeremonyHandle<Ceremony> {
 	fn spawn<Chain: ChainSigning>(
 		ceremony_id: CeremonyId,
 		outcome_sender: UnboundedSender<(CeremonyId, CeremonyOutcome<Ceremony>)>,
+		stage_duration: Duration,
 		scope: &Scope<'_, anyhow::Error>,
 	) -> Self
 	where
@@ -860,6 +891,7 @@ impl<Ceremony: CeremonyTrait> CeremonyHandle<Ceremony> {
 			message_receiver,
 			request_receiver,
 			outcome_sender,
+			stage_duration,
 		));
 
 		CeremonyHandle {
@@ -4,7 +4,8 @@ use crate::{
 	client::{
 		self,
 		ceremony_manager::{
-			CeremonyHandle, CeremonyManager, CeremonyRequestState, SigningCeremony,
+			CeremonyHandle, CeremonyManager, CeremonyRequestState, CeremonyTimeouts,
+			SigningCeremony, DEFAULT_STAGE_DURATION,
 		},
 		ceremony_runner::CeremonyRunner,
 		common::{BroadcastFailureReason, SigningFailureReason, SigningStageName},
@@ -77,6 +78,7 @@ fn new_ceremony_manager_for_test(
 		our_account_id,
 		tokio::sync::mpsc::unbounded_channel().0,
 		latest_ceremony_id,
+		CeremonyTimeouts::default(),
 	)
 }
 
@@ -119,8 +121,12 @@ fn spawn_ceremony_manager<Chain: ChainSigning>(
 	let (ceremony_request_sender, ceremony_request_receiver) = mpsc::unbounded_channel();
 	let (incoming_p2p_sender, incoming_p2p_receiver) = mpsc::unbounded_channel();
 	let (outgoing_p2p_sender, outgoing_p2p_receiver) = mpsc::unbounded_channel();
-	let ceremony_manager =
-		CeremonyManager::<Chain>::new(our_account_id, outgoing_p2p_sender, latest_ceremony_id);
+	let ceremony_manager = CeremonyManager::<Chain>::new(
+		our_account_id,
+		outgoing_p2p_sender,
+		latest_ceremony_id,
+		CeremonyTimeouts::default(),
+	);
 	tokio::spawn(ceremony_manager.run(ceremony_request_receiver, incoming_p2p_receiver));
 
 	(ceremony_request_sender, incoming_p2p_sender, outgoing_p2p_receiver)
@@ -250,6 +256,7 @@ async fn should_not_create_unauthorized_ceremony_with_invalid_ceremony_id() {
 		ACCOUNT_IDS[0].clone(),
 		tokio::sync::mpsc::unbounded_channel().0,
 		latest_ceremony_id,
+		CeremonyTimeouts::default(),
 	);
 
 	task_scope(|scope| {
@@ -320,6 +327,87 @@ async fn should_send_outcome_of_authorised_ceremony() {
 	);
 }
 
+// Ceremonies are keyed by ceremony id, so overlapping signing ceremonies progress and complete
+// independently of each other.
+#[tokio::test(start_paused = true)]
+async fn should_run_overlapping_signing_ceremonies_independently() {
+	let (ceremony_request_sender, _incoming_p2p_sender, _outgoing_p2p_receiver) =
+		spawn_ceremony_manager::<EthSigning>(ACCOUNT_IDS[0].clone(), INITIAL_LATEST_CEREMONY_ID);
+
+	let mut first_result_receiver = send_signing_request(
+		&ceremony_request_sender,
+		BTreeSet::from_iter(ACCOUNT_IDS.iter().cloned()),
+		INITIAL_LATEST_CEREMONY_ID + 1,
+	);
+
+	// Start the second ceremony while the first is still in progress
+	tokio::time::sleep(CEREMONY_TIMEOUT_DURATION / 2).await;
+	let mut second_result_receiver = send_signing_request(
+		&ceremony_request_sender,
+		BTreeSet::from_iter(ACCOUNT_IDS.iter().cloned()),
+		INITIAL_LATEST_CEREMONY_ID + 2,
+	);
+
+	let expected_outcome = Err((
+		BTreeSet::default(),
+		SigningFailureReason::BroadcastFailure(
+			BroadcastFailureReason::InsufficientVerificationMessages,
+			SigningStageName::VerifyCommitmentsBroadcast2,
+		),
+	));
+
+	// Only the first ceremony has timed out
+	tokio::time::sleep(CEREMONY_TIMEOUT_DURATION / 2).await;
+	assert_eq!(first_result_receiver.try_recv().unwrap(), expected_outcome);
+	assert!(second_result_receiver.try_recv().is_err());
+
+	// The second ceremony completes on its own schedule
+	tokio::time::sleep(CEREMONY_TIMEOUT_DURATION / 2).await;
+	assert_eq!(second_result_receiver.try_recv().unwrap(), expected_outcome);
+}
+
+#[tokio::test(start_paused = true)]
+async fn should_time_out_using_configured_stage_duration() {
+	const STAGE_DURATION: Duration = Duration::from_secs(1);
+
+	let (ceremony_request_sender, ceremony_request_receiver) = mpsc::unbounded_channel();
+	let (_incoming_p2p_sender, incoming_p2p_receiver) = mpsc::unbounded_channel();
+	let (outgoing_p2p_sender, _outgoing_p2p_receiver) = mpsc::unbounded_channel();
+	let ceremony_manager = CeremonyManager::<EthSigning>::new(
+		ACCOUNT_IDS[0].clone(),
+		outgoing_p2p_sender,
+		INITIAL_LATEST_CEREMONY_ID,
+		CeremonyTimeouts {
+			keygen_stage_duration: DEFAULT_STAGE_DURATION,
+			signing_stage_duration: STAGE_DURATION,
+		},
+	);
+	tokio::spawn(ceremony_manager.run(ceremony_request_receiver, incoming_p2p_receiver));
+
+	let mut result_receiver = send_signing_request(
+		&ceremony_request_sender,
+		BTreeSet::from_iter(ACCOUNT_IDS.iter().cloned()),
+		INITIAL_LATEST_CEREMONY_ID + 1,
+	);
+
+	// Only the first of the two broadcast stages has timed out.
+	tokio::time::sleep(STAGE_DURATION).await;
+	assert!(result_receiver.try_recv().is_err());
+
+	// Both stages time out well before the default stage duration would have elapsed.
+	tokio::time::sleep(STAGE_DURATION + Duration::from_millis(50)).await;
+	assert_eq!(
+		result_receiver.try_recv().unwrap(),
+		Err((
+			BTreeSet::default(),
+			SigningFailureReason::BroadcastFailure(
+				BroadcastFailureReason::InsufficientVerificationMessages,
+				SigningStageName::VerifyCommitmentsBroadcast2
+			),
+		))
+	);
+}
+
 #[tokio::test]
 async fn should_cleanup_unauthorised_ceremony_if_not_participating() {
 	task_scope(|scope| {
@@ -338,6 +426,7 @@ async fn should_cleanup_unauthorised_ceremony_if_not_participating() {
 				our_account_id.clone(),
 				outgoing_p2p_sender,
 				INITIAL_LATEST_CEREMONY_ID,
+				CeremonyTimeouts::default(),
 			);
 
 			// Manually spawn a ceremony runner in an unauthorised state
@@ -355,6 +444,7 @@ async fn should_cleanup_unauthorised_ceremony_if_not_participating() {
 				ceremony_runner_p2p_receiver,
 				ceremony_runner_request_receiver,
 				mpsc::unbounded_channel().0,
+				DEFAULT_STAGE_DURATION,
 			));
 
 			// Turn the task handle into a ceremony handle and insert it into the ceremony manager
@@ -20,18 +20,17 @@ use utilities::{format_iterator, metrics::CeremonyMetrics};
 use crate::{
 	client::{
 		ceremony_id_string,
-		common::{ProcessMessageResult, StageResult},
+		common::{CeremonyFailureReason, ProcessMessageResult, StageResult},
 	},
 	ChainSigning,
 };
-use state_chain_runtime::{constants::common::MAX_STAGE_DURATION_SECONDS, AccountId};
+use state_chain_runtime::AccountId;
 
 use super::{
 	ceremony_manager::{CeremonyOutcome, CeremonyTrait, DynStage, PreparedRequest},
 	common::PreProcessStageDataCheck,
 };
 
-const MAX_STAGE_DURATION: Duration = Duration::from_secs(MAX_STAGE_DURATION_SECONDS as u64);
 const INCORRECT_NUMBER_ELEMENTS: &str = "incorrect_number_of_elements";
 
 type OptionalCeremonyReturn<C> = Option<
@@ -53,6 +52,8 @@ where
 	delayed_messages: BTreeMap<AccountId, Ceremony::Data>,
 	/// This will fire on stage timeout
 	timeout_handle: Pin<Box<tokio::time::Sleep>>,
+	/// The time each stage is given to complete
+	stage_duration: Duration,
 	outcome_sender: UnboundedSender<(CeremonyId, CeremonyOutcome<Ceremony>)>,
 	_phantom: std::marker::PhantomData<Chain>,
 	metrics: CeremonyMetrics,
@@ -71,6 +72,7 @@ where
 		mut message_receiver: UnboundedReceiver<(AccountId, Ceremony::Data)>,
 		request_receiver: oneshot::Receiver<PreparedRequest<Ceremony>>,
 		outcome_sender: UnboundedSender<(CeremonyId, CeremonyOutcome<Ceremony>)>,
+		stage_duration: Duration,
 	) -> Result<()> {
 		let span = tracing::info_span!(
 			"CeremonyRunner",
@@ -79,7 +81,7 @@ where
 
 		// We always create unauthorised first, it can get promoted to
 		// an authorised one with a ceremony request
-		let mut runner = Self::new_unauthorised(outcome_sender);
+		let mut runner = Self::new_unauthorised(outcome_sender, stage_duration);
 		let mut ceremony_start: Option<Instant> = None;
 		// Fuse the oneshot future so it will not get called twice
 		let mut request_receiver = request_receiver.fuse();
@@ -113,6 +115,10 @@ where
 		if let Some(start_instant) = ceremony_start {
 			let duration = start_instant.elapsed();
 			runner.metrics.ceremony_duration.observe(duration);
+			runner.metrics.ceremony_outcome.inc(&[match &outcome {
+				Ok(_) => "success",
+				Err((_, reason)) => reason.metric_label(),
+			}]);
 			span.in_scope(|| {
 				tracing::info!("Ceremony took {}ms to complete", duration.as_millis())
 			});
@@ -126,12 +132,14 @@ where
 	/// cannot make any progress otherwise
 	fn new_unauthorised(
 		outcome_sender: UnboundedSender<(CeremonyId, CeremonyOutcome<Ceremony>)>,
+		stage_duration: Duration,
 	) -> Self {
 		CeremonyRunner {
 			stage: None,
 			delayed_messages: Default::default(),
 			// Unauthorised ceremonies cannot timeout, so just set the timeout to 0 for now.
 			timeout_handle: Box::pin(tokio::time::sleep(tokio::time::Duration::ZERO)),
+			stage_duration,
 			outcome_sender,
 			_phantom: Default::default(),
 			metrics: CeremonyMetrics::new(Chain::NAME, Ceremony::CEREMONY_TYPE),
@@ -144,6 +152,7 @@ where
 		&mut self,
 		mut initial_stage: DynStage<Ceremony>,
 	) -> OptionalCeremonyReturn<Ceremony> {
+		self.metrics.participants.set(initial_stage.ceremony_common().all_idxs.len());
 		let single_party_result = initial_stage.init(&mut self.metrics);
 
 		// This function is only ever called from a oneshot channel,
@@ -154,7 +163,7 @@ where
 		// Unlike other state transitions, we don't take into account
 		// any time left in the prior stage when receiving a ceremony request because
 		// we don't want other parties to be able to control when our stages time out.
-		self.timeout_handle = Box::pin(tokio::time::sleep(MAX_STAGE_DURATION));
+		self.timeout_handle = Box::pin(tokio::time::sleep(self.stage_duration));
 
 		if let ProcessMessageResult::Ready = single_party_result {
 			self.finalize_current_stage().await
@@ -193,7 +202,7 @@ where
 					// attacks possible.
 					{
 						let current_deadline = self.timeout_handle.as_ref().deadline();
-						self.timeout_handle.as_mut().reset(current_deadline + MAX_STAGE_DURATION);
+						self.timeout_handle.as_mut().reset(current_deadline + self.stage_duration);
 					}
 
 					if let ProcessMessageResult::Ready = single_party_result {
@@ -370,7 +379,10 @@ where
 {
 	/// This is to allow calling a private method from tests
 	pub fn new_unauthorised_for_test() -> Self {
-		Self::new_unauthorised(tokio::sync::mpsc::unbounded_channel().0)
+		Self::new_unauthorised(
+			tokio::sync::mpsc::unbounded_channel().0,
+			crate::client::ceremony_manager::DEFAULT_STAGE_DURATION,
+		)
 	}
 
 	fn get_awaited_parties_count(&self) -> Option<AuthorityCount> {
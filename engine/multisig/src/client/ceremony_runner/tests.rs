@@ -1,6 +1,8 @@
 use crate::{
 	client::{
-		ceremony_manager::{prepare_signing_request, KeygenCeremony, SigningCeremony},
+		ceremony_manager::{
+			prepare_signing_request, KeygenCeremony, SigningCeremony, DEFAULT_STAGE_DURATION,
+		},
 		common::SigningStageName,
 		gen_keygen_data_verify_hash_comm2, get_key_data_for_test,
 		helpers::{ACCOUNT_IDS, CEREMONY_TIMEOUT_DURATION, DEFAULT_SIGNING_SEED},
@@ -43,6 +45,7 @@ fn spawn_signing_ceremony_runner(
 			message_receiver,
 			request_receiver,
 			outcome_sender,
+			DEFAULT_STAGE_DURATION,
 		));
 
 	(task_handle, (message_sender, request_sender, outcome_receiver))
@@ -82,7 +85,7 @@ async fn should_ignore_non_stage_1_messages_while_unauthorised() {
 	let mut unauthorised_ceremony_runner: CeremonyRunner<
 		KeygenCeremony<EvmCryptoScheme>,
 		EthSigning,
-	> = CeremonyRunner::new_unauthorised(mpsc::unbounded_channel().0);
+	> = CeremonyRunner::new_unauthorised(mpsc::unbounded_channel().0, DEFAULT_STAGE_DURATION);
 
 	// Process a stage 2 message
 	assert_eq!(
@@ -106,7 +109,7 @@ async fn should_delay_stage_1_message_while_unauthorised() {
 
 	// Create an unauthorised ceremony
 	let mut ceremony_runner: CeremonyRunner<SigningCeremony<EvmCryptoScheme>, EthSigning> =
-		CeremonyRunner::new_unauthorised(mpsc::unbounded_channel().0);
+		CeremonyRunner::new_unauthorised(mpsc::unbounded_channel().0, DEFAULT_STAGE_DURATION);
 
 	// Process a stage 1 message (It should get delayed)
 	assert_eq!(
@@ -208,8 +211,10 @@ async fn gen_stage_1_signing_state(
 	CeremonyRunner<SigningCeremony<EvmCryptoScheme>, EthSigning>,
 	UnboundedReceiver<OutgoingMultisigStageMessages>,
 ) {
-	let mut ceremony_runner =
-		CeremonyRunner::new_unauthorised(tokio::sync::mpsc::unbounded_channel().0);
+	let mut ceremony_runner = CeremonyRunner::new_unauthorised(
+		tokio::sync::mpsc::unbounded_channel().0,
+		DEFAULT_STAGE_DURATION,
+	);
 
 	let (outgoing_p2p_sender, outgoing_p2p_receiver) = tokio::sync::mpsc::unbounded_channel();
 	let initial_stage = prepare_signing_request(
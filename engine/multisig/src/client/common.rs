@@ -92,6 +92,14 @@ impl<C: CryptoScheme> KeygenResult<C> {
 	pub fn get_agg_public_key(&self) -> C::PublicKey {
 		C::pubkey_from_point(&self.get_agg_public_key_point())
 	}
+
+	/// The party whose public key corresponds to our secret key share, if any.
+	pub fn key_share_owner(&self) -> Option<&AccountId> {
+		let own_public_key = C::Point::from_scalar(&self.key_share.x_i);
+		self.party_public_keys.iter().find_map(|(account_id, public_key)| {
+			(*public_key == own_public_key).then_some(account_id)
+		})
+	}
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
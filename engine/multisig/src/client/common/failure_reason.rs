@@ -5,6 +5,7 @@ use std::collections::BTreeSet;
 
 use utilities::format_iterator;
 
+use strum_macros::IntoStaticStr;
 use thiserror::Error;
 
 use super::{KeygenStageName, SigningStageName};
@@ -20,7 +21,7 @@ pub const KEYGEN_CEREMONY_FAILED: &str = "E5";
 pub const UNAUTHORIZED_SIGNING_ABORTED: &str = "E8";
 pub const UNAUTHORIZED_KEYGEN_ABORTED: &str = "E9";
 
-#[derive(Error, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Error, Debug, PartialEq, Eq, PartialOrd, Ord, IntoStaticStr)]
 pub enum SigningFailureReason {
 	#[error("Not participating in unauthorised ceremony")]
 	NotParticipatingInUnauthorisedCeremony,
@@ -42,7 +43,7 @@ pub enum SigningFailureReason {
 	DeveloperError(String),
 }
 
-#[derive(Error, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Error, Debug, PartialEq, Eq, PartialOrd, Ord, IntoStaticStr)]
 pub enum KeygenFailureReason {
 	#[error("Not participating in unauthorised ceremony")]
 	NotParticipatingInUnauthorisedCeremony,
@@ -80,9 +81,16 @@ const KEYGEN_REQUEST_IGNORED_PREFIX: &str = "Keygen request ignored";
 
 pub trait CeremonyFailureReason {
 	fn log(&self, reported_parties: &BTreeSet<AccountId>);
+
+	/// Coarse description of the failure (without any details), used to label metrics
+	fn metric_label(&self) -> &'static str;
 }
 
 impl CeremonyFailureReason for SigningFailureReason {
+	fn metric_label(&self) -> &'static str {
+		self.into()
+	}
+
 	fn log(&self, reported_parties: &BTreeSet<AccountId>) {
 		let reported_parties = format_iterator(reported_parties).to_string();
 		match self {
@@ -113,6 +121,10 @@ impl CeremonyFailureReason for SigningFailureReason {
 }
 
 impl CeremonyFailureReason for KeygenFailureReason {
+	fn metric_label(&self) -> &'static str {
+		self.into()
+	}
+
 	fn log(&self, reported_parties: &BTreeSet<AccountId>) {
 		let reported_parties = format_iterator(reported_parties).to_string();
 		match self {
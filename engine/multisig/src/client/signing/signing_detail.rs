@@ -178,6 +178,25 @@ pub fn aggregate_signature<C: CryptoScheme>(
 ) -> Result<C::Signature, BTreeSet<AuthorityCount>> {
 	let challenge = C::build_challenge(agg_pubkey, group_commitment, payload);
 
+	// Response shares/shards are additive, so we simply need to
+	// add them together (see step 7.c in Figure 3, page 15).
+	let z: <C::Point as ECPoint>::Scalar = responses.iter().map(|(_idx, sig)| sig.clone()).sum();
+
+	// The per-party checks are linear in the (lagrange-weighted) public key shares, commitments
+	// and responses, so their sum is a single check of the aggregate response against the
+	// aggregate key and the group commitment. If it holds, the signature is valid and we can
+	// skip checking each party individually, which is only needed to identify the culprits.
+	if C::is_party_response_valid(
+		&agg_pubkey,
+		&<C::Point as ECPoint>::Scalar::from(1),
+		&group_commitment,
+		&group_commitment,
+		&challenge,
+		&z,
+	) {
+		return Ok(C::build_signature(z, group_commitment))
+	}
+
 	let invalid_idxs: BTreeSet<AuthorityCount> = signer_idxs
 		.iter()
 		.copied()
@@ -198,11 +217,6 @@ pub fn aggregate_signature<C: CryptoScheme>(
 		.collect();
 
 	if invalid_idxs.is_empty() {
-		// Response shares/shards are additive, so we simply need to
-		// add them together (see step 7.c in Figure 3, page 15).
-		let z: <C::Point as ECPoint>::Scalar =
-			responses.iter().map(|(_idx, sig)| sig.clone()).sum();
-
 		Ok(C::build_signature(z, group_commitment))
 	} else {
 		Err(invalid_idxs)
@@ -299,4 +313,77 @@ mod tests {
 			"944dfda1d57e1848a1c99ff54e8570a98a59a4aeb0255c6609997d33b8e02c00"
 		);
 	}
+
+	#[test]
+	fn aggregation_identifies_invalid_responses() {
+		use rand::SeedableRng;
+		let mut rng = Rng::from_seed([0; 32]);
+
+		let payload = SigningPayload(hex::decode(MESSAGE_HASH).unwrap().try_into().unwrap());
+		let idxs = BTreeSet::from_iter(vec![1u32, 2, 3]);
+
+		let lagrange_coefficients: BTreeMap<_, _> = idxs
+			.iter()
+			.map(|idx| (*idx, get_lagrange_coeff::<Point>(*idx, &idxs)))
+			.collect();
+		let secret_shares: BTreeMap<_, _> =
+			idxs.iter().map(|idx| (*idx, Scalar::random(&mut rng))).collect();
+		let pubkeys: BTreeMap<_, _> =
+			secret_shares.iter().map(|(idx, x_i)| (*idx, Point::from_scalar(x_i))).collect();
+		let agg_pubkey: Point =
+			pubkeys.iter().map(|(idx, y_i)| *y_i * &lagrange_coefficients[idx]).sum();
+
+		let nonces: BTreeMap<_, _> = idxs
+			.iter()
+			.map(|idx| (*idx, SecretNoncePair::<Point>::sample_random(&mut rng)))
+			.collect();
+		let commitments: BTreeMap<_, _> = nonces
+			.iter()
+			.map(|(idx, nonces)| (*idx, SigningCommitment { d: nonces.d_pub, e: nonces.e_pub }))
+			.collect();
+		let bindings = generate_bindings::<EvmCryptoScheme>(&payload, &commitments, &idxs);
+		let bound_commitments: BTreeMap<_, _> = commitments
+			.iter()
+			.map(|(idx, comm)| (*idx, comm.d + comm.e * bindings[idx].clone()))
+			.collect();
+		let group_commitment: Point = bound_commitments.values().cloned().sum();
+
+		let mut responses: BTreeMap<_, _> = idxs
+			.iter()
+			.map(|idx| {
+				(
+					*idx,
+					generate_local_sig::<EvmCryptoScheme>(
+						&payload,
+						&KeyShare { y: agg_pubkey, x_i: secret_shares[idx].clone() },
+						&nonces[idx],
+						&bindings,
+						group_commitment,
+						*idx,
+						&idxs,
+					),
+				)
+			})
+			.collect();
+
+		let aggregate = |responses: &BTreeMap<_, _>| {
+			aggregate_signature::<EvmCryptoScheme>(
+				&payload,
+				&idxs,
+				agg_pubkey,
+				&pubkeys,
+				group_commitment,
+				&bound_commitments,
+				responses,
+				&lagrange_coefficients,
+			)
+		};
+
+		assert!(aggregate(&responses).is_ok());
+
+		// A single bad response fails the aggregate check, and is then pinpointed.
+		let bad_response = responses[&2].clone() + Scalar::from(1);
+		responses.insert(2, bad_response);
+		assert_eq!(aggregate(&responses), Err(BTreeSet::from_iter(vec![2])));
+	}
 }
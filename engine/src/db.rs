@@ -1,13 +1,29 @@
+mod key_share_encryption;
 pub mod persistent;
 use std::{collections::HashMap, sync::Arc};
 
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use cf_primitives::EpochIndex;
+use key_share_encryption::{EncryptedKeyShare, KeyShareCipher, SALT_SIZE};
 pub use persistent::PersistentKeyDB;
+use serde::{Deserialize, Serialize};
 
 use multisig::{
 	client::{key_store_api::KeyStoreAPI, KeygenResultInfo},
 	ChainSigning, KeyId,
 };
 
+/// Version of the format produced by [KeyStore::export_key]. Stored as the first byte of the
+/// exported blob, so that the format can be changed without breaking old exports.
+const KEY_EXPORT_VERSION: u8 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct KeyExport {
+	salt: [u8; SALT_SIZE],
+	/// The epoch index and `KeygenResultInfo` of the key
+	encrypted_key: EncryptedKeyShare,
+}
+
 /// A gateway for accessing key data from persistent memory
 pub struct KeyStore<C>
 where
@@ -22,6 +38,56 @@ impl<C: ChainSigning> KeyStore<C> {
 	pub fn new(db: Arc<PersistentKeyDB>) -> Self {
 		KeyStore { keys: db.load_keys::<C>(), db }
 	}
+
+	/// Export the key with the given id as a versioned blob, encrypted with a key derived from
+	/// `passphrase`, so that it can be moved to another node and loaded with [Self::import_key].
+	pub fn export_key(&self, key_id: &KeyId, passphrase: &str) -> Result<Vec<u8>> {
+		let key = self.keys.get(key_id).ok_or_else(|| anyhow!("Unknown key {key_id}"))?;
+
+		let salt = rand::random::<[u8; SALT_SIZE]>();
+		let encrypted_key = KeyShareCipher::new(passphrase, &salt).encrypt(
+			&bincode::serialize(&(key_id.epoch_index(), key))
+				.expect("Serialization is not expected to fail"),
+		);
+
+		Ok([
+			&[KEY_EXPORT_VERSION][..],
+			&bincode::serialize(&KeyExport { salt, encrypted_key })
+				.expect("Serialization is not expected to fail"),
+		]
+		.concat())
+	}
+
+	/// Import a key that was exported with [Self::export_key] and save it to persistent memory.
+	/// The key is rejected if its secret share does not match any of its party public keys.
+	pub fn import_key(&mut self, bytes: &[u8], passphrase: &str) -> Result<KeyId> {
+		let (epoch_index, key): (EpochIndex, KeygenResultInfo<C::CryptoScheme>) =
+			match bytes.split_first() {
+				Some((&KEY_EXPORT_VERSION, data)) => {
+					let KeyExport { salt, encrypted_key } =
+						bincode::deserialize(data).context("Failed to deserialize key export")?;
+					bincode::deserialize(
+						&KeyShareCipher::new(passphrase, &salt).decrypt(&encrypted_key)?,
+					)
+					.context("Failed to deserialize exported key")?
+				},
+				Some((version, _)) => bail!("Unsupported key export version {version}"),
+				None => bail!("Key export is empty"),
+			};
+
+		let owner = key
+			.key
+			.key_share_owner()
+			.ok_or_else(|| anyhow!("Key share does not match any of the party public keys"))?;
+		ensure!(
+			key.validator_mapping.get_idx(owner).is_some(),
+			"Key share belongs to a party that is not a participant of the key"
+		);
+
+		let key_id = KeyId::new(epoch_index, key.key.get_agg_public_key());
+		self.set_key(key_id.clone(), key);
+		Ok(key_id)
+	}
 }
 
 impl<C: ChainSigning> KeyStoreAPI<C> for KeyStore<C> {
@@ -88,4 +154,76 @@ mod tests {
 			stored_keygen_result_info
 		);
 	}
+
+	#[test]
+	fn can_export_and_import_key() {
+		const PASSPHRASE: &str = "correct horse battery staple";
+
+		let (public_key, key_data) = keygen::generate_key_data::<EvmCryptoScheme>(
+			BTreeSet::from([AccountId::new([1; 32])]),
+			&mut Rng::from_entropy(),
+		);
+		let keygen_result_info = key_data.values().next().unwrap().clone();
+		let key_id = KeyId::new(7, public_key);
+
+		let (_dir, db_file) = utilities::testing::new_temp_directory_with_nonexistent_file();
+		let mut key_store = KeyStore::<EthSigning>::new(Arc::new(
+			PersistentKeyDB::open_and_migrate_to_latest(&db_file, None)
+				.expect("Failed to open database"),
+		));
+		key_store.set_key(key_id.clone(), keygen_result_info.clone());
+
+		let exported_key = key_store.export_key(&key_id, PASSPHRASE).unwrap();
+
+		// Import the key on a "different machine"
+		let (_dir, db_file) = utilities::testing::new_temp_directory_with_nonexistent_file();
+		let mut key_store = KeyStore::<EthSigning>::new(Arc::new(
+			PersistentKeyDB::open_and_migrate_to_latest(&db_file, None)
+				.expect("Failed to open database"),
+		));
+
+		assert!(key_store.import_key(&exported_key, "incorrect passphrase").is_err());
+		assert!(key_store.get_key(&key_id).is_none());
+
+		assert_eq!(key_store.import_key(&exported_key, PASSPHRASE).unwrap(), key_id);
+
+		// The imported key is persisted
+		let key_store = KeyStore::<EthSigning>::new(Arc::new(
+			PersistentKeyDB::open_and_migrate_to_latest(&db_file, None)
+				.expect("Failed to open database"),
+		));
+		assert_eq!(key_store.get_key(&key_id).expect("Key not found in db"), keygen_result_info);
+	}
+
+	#[test]
+	fn should_reject_imported_key_that_does_not_match_its_party_public_keys() {
+		const PASSPHRASE: &str = "correct horse battery staple";
+
+		let (public_key, key_data) = keygen::generate_key_data::<EvmCryptoScheme>(
+			BTreeSet::from([AccountId::new([1; 32]), AccountId::new([2; 32])]),
+			&mut Rng::from_entropy(),
+		);
+		let key_id = KeyId::new(7, public_key);
+
+		// Replace the party public keys with those of an unrelated key
+		let (_, other_key_data) = keygen::generate_key_data::<EvmCryptoScheme>(
+			BTreeSet::from([AccountId::new([1; 32]), AccountId::new([2; 32])]),
+			&mut Rng::from_entropy(),
+		);
+		let mut keygen_result_info = key_data[&AccountId::new([1; 32])].clone();
+		let mut keygen_result = (*keygen_result_info.key).clone();
+		keygen_result.party_public_keys =
+			other_key_data[&AccountId::new([1; 32])].key.party_public_keys.clone();
+		keygen_result_info.key = Arc::new(keygen_result);
+
+		let (_dir, db_file) = utilities::testing::new_temp_directory_with_nonexistent_file();
+		let mut key_store = KeyStore::<EthSigning>::new(Arc::new(
+			PersistentKeyDB::open_and_migrate_to_latest(&db_file, None)
+				.expect("Failed to open database"),
+		));
+		key_store.set_key(key_id.clone(), keygen_result_info);
+
+		let exported_key = key_store.export_key(&key_id, PASSPHRASE).unwrap();
+		assert!(key_store.import_key(&exported_key, PASSPHRASE).is_err());
+	}
 }
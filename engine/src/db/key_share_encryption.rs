@@ -0,0 +1,57 @@
+use aes_gcm::{
+	aead::{Aead, KeyInit},
+	Aes256Gcm, Key, Nonce,
+};
+use anyhow::{anyhow, Result};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+/// Size of the random salt used when deriving the encryption key from a passphrase
+pub const SALT_SIZE: usize = 16;
+
+/// Number of PBKDF2 iterations used to derive the encryption key from a passphrase
+const PBKDF2_ROUNDS: u32 = 600_000;
+
+const NONCE_SIZE: usize = 12;
+
+/// A key share (or any other secret) encrypted with AES-256-GCM
+#[derive(Serialize, Deserialize)]
+pub struct EncryptedKeyShare {
+	nonce: [u8; NONCE_SIZE],
+	ciphertext: Vec<u8>,
+}
+
+/// Encrypts and decrypts key shares with a key derived from an operator-supplied passphrase
+pub struct KeyShareCipher {
+	cipher: Aes256Gcm,
+}
+
+impl KeyShareCipher {
+	pub fn new(passphrase: &str, salt: &[u8]) -> Self {
+		let mut key = Zeroizing::new([0u8; 32]);
+		pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, PBKDF2_ROUNDS, &mut key[..]);
+
+		KeyShareCipher { cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key[..])) }
+	}
+
+	pub fn encrypt(&self, plaintext: &[u8]) -> EncryptedKeyShare {
+		let mut nonce = [0u8; NONCE_SIZE];
+		rand::thread_rng().fill_bytes(&mut nonce);
+
+		EncryptedKeyShare {
+			nonce,
+			ciphertext: self
+				.cipher
+				.encrypt(Nonce::from_slice(&nonce), plaintext)
+				.expect("Encryption is not expected to fail"),
+		}
+	}
+
+	pub fn decrypt(&self, encrypted: &EncryptedKeyShare) -> Result<Vec<u8>> {
+		self.cipher
+			.decrypt(Nonce::from_slice(&encrypted.nonce), encrypted.ciphertext.as_ref())
+			.map_err(|_| anyhow!("Failed to decrypt key share. Is the passphrase correct?"))
+	}
+}
@@ -13,6 +13,7 @@ use multisig::{client::KeygenResultInfo, ChainSigning, KeyId, CHAIN_TAG_SIZE};
 
 use anyhow::{anyhow, bail, Context, Result};
 
+use super::key_share_encryption::{EncryptedKeyShare, KeyShareCipher, SALT_SIZE};
 use rocksdb_kv::{RocksDBKeyValueStore, PREFIX_SIZE};
 
 /// Name of the directory that the backups will go into (only created before migrations)
@@ -34,6 +35,11 @@ const PROCESSED_BLOCKS_PARTIAL_PREFIX: &[u8; PARTIAL_PREFIX_SIZE] = b"seen____";
 /// Key used to store the `LATEST_SCHEMA_VERSION` value in the `METADATA_COLUMN`
 const DB_SCHEMA_VERSION_KEY: &[u8; 17] = b"db_schema_version";
 const GENESIS_HASH_KEY: &[u8; 12] = b"genesis_hash";
+/// Salt used to derive the key share encryption key. Only present if key shares are encrypted.
+const KEY_SHARE_ENCRYPTION_SALT_KEY: &[u8; 25] = b"key_share_encryption_salt";
+/// A known value encrypted with the key share encryption key, used to check the passphrase
+const KEY_SHARE_ENCRYPTION_CHECK_KEY: &[u8; 26] = b"key_share_encryption_check";
+const KEY_SHARE_ENCRYPTION_CHECK_VALUE: &[u8] = b"chainflip";
 
 /// Used to specify whether a backup should be created, and if so,
 /// the provided path is used to derive the name of the backup
@@ -46,6 +52,8 @@ enum BackupOption<'a> {
 pub struct PersistentKeyDB {
 	/// Underlying key-value database instance
 	kv_db: RocksDBKeyValueStore,
+	/// Used to encrypt key shares at rest, if enabled
+	key_share_cipher: Option<KeyShareCipher>,
 }
 
 impl PersistentKeyDB {
@@ -70,7 +78,8 @@ impl PersistentKeyDB {
 	) -> Result<Self> {
 		let is_existing_db = db_path.exists();
 
-		let db = PersistentKeyDB { kv_db: RocksDBKeyValueStore::open(db_path)? };
+		let db =
+			PersistentKeyDB { kv_db: RocksDBKeyValueStore::open(db_path)?, key_share_cipher: None };
 
 		// Only create a backup if there is an existing db that we don't
 		// want to accidentally corrupt
@@ -98,23 +107,107 @@ impl PersistentKeyDB {
 		Ok(db)
 	}
 
+	/// Encrypt key shares at rest using a key derived from the given passphrase. If the key shares
+	/// in the db are not encrypted yet, they are encrypted in place.
+	pub fn with_key_share_encryption(mut self, passphrase: &str) -> Result<Self> {
+		let cipher = match (
+			self.kv_db.get_metadata(KEY_SHARE_ENCRYPTION_SALT_KEY),
+			self.kv_db.get_metadata(KEY_SHARE_ENCRYPTION_CHECK_KEY),
+		) {
+			(Some(salt), Some(check)) => {
+				let cipher = KeyShareCipher::new(passphrase, &salt);
+				cipher
+					.decrypt(
+						&bincode::deserialize(&check)
+							.context("Failed to deserialize key share encryption check")?,
+					)
+					.context("Incorrect key share passphrase")?;
+				cipher
+			},
+			_ => {
+				let salt = rand::random::<[u8; SALT_SIZE]>();
+				let cipher = KeyShareCipher::new(passphrase, &salt);
+
+				let mut batch = self.kv_db.create_batch();
+				let mut encrypted_count = 0;
+				for (key, value) in
+					self.kv_db.get_raw_data_starting_with(KEYGEN_DATA_PARTIAL_PREFIX)
+				{
+					batch.put_value(&key, &serialize_encrypted(&cipher, &value));
+					encrypted_count += 1;
+				}
+				batch.put_metadata(KEY_SHARE_ENCRYPTION_SALT_KEY, salt);
+				batch.put_metadata(
+					KEY_SHARE_ENCRYPTION_CHECK_KEY,
+					serialize_encrypted(&cipher, KEY_SHARE_ENCRYPTION_CHECK_VALUE),
+				);
+				batch.write().context("Failed to encrypt existing key shares")?;
+
+				info!("Enabled key share encryption, encrypted {encrypted_count} existing keys");
+				cipher
+			},
+		};
+
+		self.key_share_cipher = Some(cipher);
+		Ok(self)
+	}
+
+	fn key_shares_are_encrypted(&self) -> bool {
+		self.kv_db.get_metadata(KEY_SHARE_ENCRYPTION_SALT_KEY).is_some()
+	}
+
 	/// Write the keyshare to the db, indexed by the key id
 	pub fn update_key<C: ChainSigning>(
 		&self,
 		key_id: &KeyId,
 		keygen_result_info: &KeygenResultInfo<C::CryptoScheme>,
 	) {
-		self.kv_db
-			.put_data(&keygen_data_prefix::<C>(), &key_id, &keygen_result_info)
-			.unwrap_or_else(|e| panic!("Failed to update key {}. Error: {}", &key_id, e));
+		let result = match &self.key_share_cipher {
+			Some(cipher) => self.kv_db.put_data(
+				&keygen_data_prefix::<C>(),
+				&key_id,
+				&cipher.encrypt(
+					&bincode::serialize(keygen_result_info)
+						.expect("Serialization is not expected to fail"),
+				),
+			),
+			None => {
+				assert!(
+					!self.key_shares_are_encrypted(),
+					"Key shares are encrypted, but no passphrase was provided"
+				);
+				self.kv_db.put_data(&keygen_data_prefix::<C>(), &key_id, &keygen_result_info)
+			},
+		};
+		result.unwrap_or_else(|e| panic!("Failed to update key {}. Error: {}", &key_id, e));
 	}
 
 	pub fn load_keys<C: ChainSigning>(&self) -> HashMap<KeyId, KeygenResultInfo<C::CryptoScheme>> {
 		let span = info_span!("PersistentKeyDB");
 		let _entered = span.enter();
 
-		let keys: HashMap<_, _> =
-			self.kv_db.get_data_for_prefix(&keygen_data_prefix::<C>()).collect();
+		let keys: HashMap<_, _> = match &self.key_share_cipher {
+			Some(cipher) => self
+				.kv_db
+				.get_data_for_prefix::<KeyId, EncryptedKeyShare>(&keygen_data_prefix::<C>())
+				.map(|(key_id, encrypted_key_share)| {
+					let keygen_result_info = cipher
+						.decrypt(&encrypted_key_share)
+						.and_then(|data| {
+							bincode::deserialize(&data).context("Deserialization failed")
+						})
+						.unwrap_or_else(|e| panic!("Failed to load key {key_id}. Error: {e}"));
+					(key_id, keygen_result_info)
+				})
+				.collect(),
+			None => {
+				assert!(
+					!self.key_shares_are_encrypted(),
+					"Key shares are encrypted, but no passphrase was provided"
+				);
+				self.kv_db.get_data_for_prefix(&keygen_data_prefix::<C>()).collect()
+			},
+		};
 
 		for key in &keys {
 			tracing::trace!("Loaded {} key from the database: {}", C::NAME, key.0);
@@ -192,6 +285,10 @@ fn keygen_data_prefix<C: ChainSigning>() -> Vec<u8> {
 	[&KEYGEN_DATA_PARTIAL_PREFIX[..], &(C::CHAIN_TAG.to_bytes())[..]].concat()
 }
 
+fn serialize_encrypted(cipher: &KeyShareCipher, plaintext: &[u8]) -> Vec<u8> {
+	bincode::serialize(&cipher.encrypt(plaintext)).expect("Serialization is not expected to fail")
+}
+
 fn processed_blocks_prefix(witnessner_name: &str) -> Vec<u8> {
 	[PROCESSED_BLOCKS_PARTIAL_PREFIX, witnessner_name.as_bytes()].concat()
 }
@@ -1,6 +1,9 @@
 use std::path::Path;
 
-use rocksdb::{ColumnFamily, ColumnFamilyDescriptor, Options, WriteBatch, DB};
+use rocksdb::{
+	ColumnFamily, ColumnFamilyDescriptor, Direction, IteratorMode, Options, ReadOptions,
+	WriteBatch, DB,
+};
 use serde::{de::DeserializeOwned, Serialize};
 
 use anyhow::{Context, Result};
@@ -97,6 +100,27 @@ impl RocksDBKeyValueStore {
 			})
 	}
 
+	/// Returns the raw keys (including their prefix) and values of all data whose key starts with
+	/// `key_start`. Unlike [Self::get_data_for_prefix], `key_start` may be shorter than
+	/// [PREFIX_SIZE].
+	pub fn get_raw_data_starting_with<'a>(
+		&'a self,
+		key_start: &'a [u8],
+	) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a {
+		// The prefix extractor only applies to full prefixes, so a total order seek is required.
+		let mut read_options = ReadOptions::default();
+		read_options.set_total_order_seek(true);
+
+		self.db
+			.iterator_cf_opt(
+				get_data_column_handle(&self.db),
+				read_options,
+				IteratorMode::From(key_start, Direction::Forward),
+			)
+			.map(|result| result.expect("iterator should not fail"))
+			.take_while(move |(key, _)| key.starts_with(key_start))
+	}
+
 	pub fn put_metadata<V>(&self, key: &[u8], value: V) -> Result<()>
 	where
 		V: AsRef<[u8]>,
@@ -123,7 +147,6 @@ pub struct KVWriteBatch<'a> {
 }
 
 impl<'a> KVWriteBatch<'a> {
-	#[allow(dead_code)]
 	pub fn put_value(&mut self, key: &[u8], value: &[u8]) {
 		self.batch.put_cf(get_data_column_handle(self.db), key, value);
 	}
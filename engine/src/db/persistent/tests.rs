@@ -311,3 +311,97 @@ fn test_migration_to_latest_from_0() {
 
 	assert_eq!(db.get_schema_version().unwrap(), LATEST_SCHEMA_VERSION);
 }
+
+#[test]
+fn can_load_encrypted_keys() {
+	type Scheme = EthSigning;
+	const PASSPHRASE: &str = "correct horse battery staple";
+
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+	let key_id = KeyId::new(GENESIS_EPOCH, [0; 33]);
+
+	{
+		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None)
+			.unwrap()
+			.with_key_share_encryption(PASSPHRASE)
+			.unwrap();
+
+		p_db.update_key::<Scheme>(
+			&key_id,
+			&get_single_key_data::<<Scheme as ChainSigning>::CryptoScheme>(),
+		);
+
+		// The key share is not stored in plaintext
+		assert!(p_db
+			.kv_db
+			.get_data::<_, KeygenResultInfo<<Scheme as ChainSigning>::CryptoScheme>>(
+				&keygen_data_prefix::<Scheme>(),
+				&key_id,
+			)
+			.is_err());
+	}
+
+	let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None)
+		.unwrap()
+		.with_key_share_encryption(PASSPHRASE)
+		.unwrap();
+	assert!(p_db.load_keys::<Scheme>().contains_key(&key_id));
+}
+
+#[test]
+fn existing_keys_are_encrypted_when_encryption_is_enabled() {
+	type Scheme = EthSigning;
+	const PASSPHRASE: &str = "correct horse battery staple";
+
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+	let key_id = KeyId::new(GENESIS_EPOCH, [0; 33]);
+
+	{
+		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+		p_db.update_key::<Scheme>(
+			&key_id,
+			&get_single_key_data::<<Scheme as ChainSigning>::CryptoScheme>(),
+		);
+	}
+
+	// Enabling encryption on each load must only encrypt the plaintext keys once
+	for _ in 0..2 {
+		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None)
+			.unwrap()
+			.with_key_share_encryption(PASSPHRASE)
+			.unwrap();
+		assert!(p_db.load_keys::<Scheme>().contains_key(&key_id));
+	}
+}
+
+#[test]
+fn should_error_if_key_share_passphrase_is_incorrect() {
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+
+	{
+		assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None)
+			.unwrap()
+			.with_key_share_encryption("correct horse battery staple"));
+	}
+
+	assert!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None)
+		.unwrap()
+		.with_key_share_encryption("incorrect passphrase")
+		.is_err());
+}
+
+#[test]
+#[should_panic]
+fn should_panic_loading_encrypted_keys_without_passphrase() {
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+
+	{
+		assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None)
+			.unwrap()
+			.with_key_share_encryption("correct horse battery staple"));
+	}
+
+	PersistentKeyDB::open_and_migrate_to_latest(&db_path, None)
+		.unwrap()
+		.load_keys::<EthSigning>();
+}
@@ -10,9 +10,9 @@ use futures_core::Future;
 use utilities::task_scope::Scope;
 
 use crate::{
-	evm::rpc::{EvmRpcApi, EvmSigningRpcApi},
-	retrier::{Attempt, RequestLog, RetrierClient},
-	settings::{NodeContainer, WsHttpEndpoints},
+	evm::rpc::{get_logs_chunked, EvmRpcApi, EvmSigningRpcApi},
+	retrier::{Attempt, NoRetryLimit, RequestLog, RetrierClient},
+	settings::{EvmRequestTimeouts, NodeContainer, WsHttpEndpoints},
 	witness::common::chain_source::{ChainClient, Header},
 };
 use std::{path::PathBuf, time::Duration};
@@ -32,12 +32,18 @@ pub struct EvmRetryRpcClient<Rpc: EvmRpcApi> {
 	sub_retry_client: RetrierClient<ReconnectSubscriptionClient>,
 	chain_name: &'static str,
 	witness_period: u64,
+	request_timeouts: EvmRequestTimeouts,
 }
 
 const ETHERS_RPC_TIMEOUT: Duration = Duration::from_millis(4 * 1000);
 const MAX_CONCURRENT_SUBMISSIONS: u32 = 100;
 
 const MAX_BROADCAST_RETRIES: Attempt = 2;
+/// Calls that revert fail the same way on every attempt, so they are only retried a few times.
+const MAX_CALL_RETRIES: Attempt = 3;
+
+/// The maximum number of blocks to request logs for in a single request.
+const LOG_RANGE_CHUNK_SIZE: u64 = 1_000;
 
 impl<Rpc: EvmRpcApi> EvmRetryRpcClient<Rpc> {
 	fn from_inner_clients<ClientFut: Future<Output = Rpc> + Send + 'static>(
@@ -50,6 +56,7 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcClient<Rpc> {
 		evm_subscription_client_name: &'static str,
 		chain_name: &'static str,
 		witness_period: u64,
+		request_timeouts: EvmRequestTimeouts,
 	) -> Self {
 		let sub_client = ReconnectSubscriptionClient::new(
 			nodes.primary.ws_endpoint,
@@ -80,6 +87,7 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcClient<Rpc> {
 			),
 			chain_name,
 			witness_period,
+			request_timeouts,
 		}
 	}
 }
@@ -93,6 +101,7 @@ impl EvmRetryRpcClient<EvmRpcClient> {
 		evm_subscription_client_name: &'static str,
 		chain_name: &'static str,
 		witness_period: u64,
+		request_timeouts: EvmRequestTimeouts,
 	) -> Result<Self> {
 		let rpc_client = EvmRpcClient::new(
 			nodes.primary.http_endpoint.clone(),
@@ -118,6 +127,7 @@ impl EvmRetryRpcClient<EvmRpcClient> {
 			evm_subscription_client_name,
 			chain_name,
 			witness_period,
+			request_timeouts,
 		))
 	}
 }
@@ -132,6 +142,7 @@ impl EvmRetryRpcClient<EvmRpcSigningClient> {
 		evm_subscription_client_name: &'static str,
 		chain_name: &'static str,
 		witness_period: u64,
+		request_timeouts: EvmRequestTimeouts,
 	) -> Result<Self> {
 		let rpc_client = EvmRpcSigningClient::new(
 			private_key_file.clone(),
@@ -163,6 +174,7 @@ impl EvmRetryRpcClient<EvmRpcSigningClient> {
 			evm_subscription_client_name,
 			chain_name,
 			witness_period,
+			request_timeouts,
 		))
 	}
 }
@@ -193,6 +205,12 @@ pub trait EvmRetryRpcApi: Clone {
 	) -> FeeHistory;
 
 	async fn get_transaction(&self, tx_hash: H256) -> Transaction;
+
+	async fn call(
+		&self,
+		req: Eip1559TransactionRequest,
+		block: Option<BlockNumber>,
+	) -> anyhow::Result<Bytes>;
 }
 
 #[async_trait::async_trait]
@@ -212,7 +230,7 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcApi for EvmRetryRpcClient<Rpc> {
 	) -> Vec<Log> {
 		assert!(!range.is_empty());
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new(
 					"get_logs_range".to_string(),
 					Some(format!("{range:?}, {contract_address:?}")),
@@ -221,24 +239,19 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcApi for EvmRetryRpcClient<Rpc> {
 					let range = range.clone();
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move {
-						client
-							.get_logs(
-								// The `from_block` and `to_block` are inclusive
-								Filter::new()
-									.address(contract_address)
-									.from_block(*range.start())
-									.to_block(*range.end()),
-							)
+						get_logs_chunked(&client, range, contract_address, LOG_RANGE_CHUNK_SIZE)
 							.await
 					})
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.logs_ms),
 			)
 			.await
 	}
 
 	async fn get_logs(&self, block_hash: H256, contract_address: H160) -> Vec<Log> {
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new(
 					"get_logs".to_string(),
 					Some(format!("{block_hash:?}, {contract_address:?}")),
@@ -253,54 +266,64 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcApi for EvmRetryRpcClient<Rpc> {
 							.await
 					})
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.logs_ms),
 			)
 			.await
 	}
 
 	async fn chain_id(&self) -> U256 {
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new("chain_id".to_string(), None),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.chain_id().await })
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.read_ms),
 			)
 			.await
 	}
 
 	async fn transaction_receipt(&self, tx_hash: H256) -> TransactionReceipt {
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new("transaction_receipt".to_string(), Some(format!("{tx_hash:?}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.transaction_receipt(tx_hash).await })
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.read_ms),
 			)
 			.await
 	}
 
 	async fn block(&self, block_number: U64) -> Block<H256> {
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new("block".to_string(), Some(format!("{block_number}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.block(block_number).await })
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.read_ms),
 			)
 			.await
 	}
 
 	async fn block_with_txs(&self, block_number: U64) -> Block<Transaction> {
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new("block_with_txs".to_string(), Some(format!("{block_number}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.block_with_txs(block_number).await })
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.read_ms),
 			)
 			.await
 	}
@@ -312,7 +335,7 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcApi for EvmRetryRpcClient<Rpc> {
 		reward_percentiles: Vec<f64>,
 	) -> FeeHistory {
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new(
 					"fee_history".to_string(),
 					Some(format!("{block_count}, {newest_block}, {reward_percentiles:?}")),
@@ -324,18 +347,41 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcApi for EvmRetryRpcClient<Rpc> {
 						client.fee_history(block_count, newest_block, &reward_percentiles).await
 					})
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.fee_history_ms),
 			)
 			.await
 	}
 
 	async fn get_transaction(&self, tx_hash: H256) -> Transaction {
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new("get_transaction".to_string(), Some(format!("{tx_hash:?}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.get_transaction(tx_hash).await })
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.read_ms),
+			)
+			.await
+	}
+
+	async fn call(
+		&self,
+		req: Eip1559TransactionRequest,
+		block: Option<BlockNumber>,
+	) -> anyhow::Result<Bytes> {
+		self.rpc_retry_client
+			.request_with_timeout(
+				RequestLog::new("call".to_string(), Some(format!("{req:?}, {block:?}"))),
+				Box::pin(move |client| {
+					let req = req.clone();
+					#[allow(clippy::redundant_async_block)]
+					Box::pin(async move { client.call(&req, block).await })
+				}),
+				MAX_CALL_RETRIES,
+				Duration::from_millis(self.request_timeouts.read_ms),
 			)
 			.await
 	}
@@ -350,7 +396,7 @@ impl<Rpc: EvmSigningRpcApi> EvmRetrySigningRpcApi for EvmRetryRpcClient<Rpc> {
 	) -> anyhow::Result<TxHash> {
 		let s = self.chain_name.to_owned();
 		self.rpc_retry_client
-			.request_with_limit(
+			.request_with_timeout(
 				RequestLog::new("broadcast_transaction".to_string(), Some(format!("{tx:?}"))),
 				Box::pin(move |client| {
 					let tx = tx.clone();
@@ -398,6 +444,7 @@ impl<Rpc: EvmSigningRpcApi> EvmRetrySigningRpcApi for EvmRetryRpcClient<Rpc> {
 					})
 				}),
 				MAX_BROADCAST_RETRIES,
+				Duration::from_millis(self.request_timeouts.send_ms),
 			)
 			.await
 	}
@@ -440,7 +487,7 @@ impl<Rpc: EvmRpcApi> ChainClient for EvmRetryRpcClient<Rpc> {
 		let witness_period = self.witness_period;
 		assert!(witness_period::is_block_witness_root(witness_period, index));
 		self.rpc_retry_client
-			.request(
+			.request_with_timeout(
 				RequestLog::new("header_at_index".to_string(), Some(format!("{index}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
@@ -490,6 +537,8 @@ impl<Rpc: EvmRpcApi> ChainClient for EvmRetryRpcClient<Rpc> {
 						})
 					})
 				}),
+				NoRetryLimit,
+				Duration::from_millis(self.request_timeouts.read_ms),
 			)
 			.await
 	}
@@ -538,6 +587,8 @@ pub mod mocks {
 			) -> FeeHistory;
 
 			async fn get_transaction(&self, tx_hash: H256) -> Transaction;
+
+			async fn call(&self, req: Eip1559TransactionRequest, block: Option<BlockNumber>) -> anyhow::Result<Bytes>;
 		}
 	}
 }
@@ -550,6 +601,83 @@ mod tests {
 	use utilities::task_scope::task_scope;
 
 	use super::*;
+	use crate::evm::rpc::mocks::MockEvmRpcClient;
+
+	fn retry_client_returning(
+		scope: &Scope<'_, anyhow::Error>,
+		call_result: impl Fn() -> Result<Bytes> + Clone + Send + Sync + 'static,
+	) -> EvmRetryRpcClient<MockEvmRpcClient> {
+		let mut rpc_client = MockEvmRpcClient::new();
+		rpc_client.expect_clone().returning(move || {
+			let mut rpc_client = MockEvmRpcClient::new();
+			let call_result = call_result.clone();
+			rpc_client.expect_call().returning(move |_, _| call_result());
+			rpc_client
+		});
+
+		EvmRetryRpcClient::from_inner_clients(
+			scope,
+			NodeContainer {
+				primary: WsHttpEndpoints {
+					ws_endpoint: "ws://127.0.0.1:8546".into(),
+					http_endpoint: "http://127.0.0.1:8545".into(),
+				},
+				backup: None,
+			},
+			U256::from(1337u64),
+			futures::future::ready(rpc_client),
+			None,
+			"eth_rpc",
+			"eth_subscribe",
+			"Ethereum",
+			Ethereum::WITNESS_PERIOD,
+			EvmRequestTimeouts::default(),
+		)
+	}
+
+	#[tokio::test]
+	async fn call_returns_the_output_of_the_call() {
+		task_scope(|scope| {
+			async move {
+				let minimum_stake = U256::from(1_000u64);
+				let retry_client = retry_client_returning(scope, move || {
+					Ok(ethers::abi::encode(&[ethers::abi::Token::Uint(minimum_stake)]).into())
+				});
+
+				let output = retry_client.call(Eip1559TransactionRequest::default(), None).await?;
+
+				assert_eq!(
+					ethers::abi::decode(&[ethers::abi::ParamType::Uint(256)], &output)?,
+					vec![ethers::abi::Token::Uint(minimum_stake)]
+				);
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap()
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn call_gives_up_when_the_call_keeps_reverting() {
+		task_scope(|scope| {
+			async move {
+				let retry_client =
+					retry_client_returning(scope, || Err(anyhow::anyhow!("execution reverted")));
+
+				assert!(retry_client
+					.call(Eip1559TransactionRequest::default(), Some(BlockNumber::Latest))
+					.await
+					.is_err());
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap()
+	}
 
 	#[tokio::test]
 	#[ignore = "requires a local node"]
@@ -567,6 +695,7 @@ mod tests {
 					"eth_subscribe",
 					"Ethereum",
 					Ethereum::WITNESS_PERIOD,
+					settings.eth.request_timeouts,
 				)
 				.unwrap();
 
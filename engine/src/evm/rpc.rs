@@ -9,7 +9,8 @@ use utilities::redact_endpoint_secret::SecretUrl;
 
 use crate::constants::{RPC_RETRY_CONNECTION_INTERVAL, SYNC_POLL_INTERVAL};
 use anyhow::{anyhow, Context, Result};
-use std::{path::PathBuf, str::FromStr, sync::Arc, time::Instant};
+use futures::{StreamExt, TryStreamExt};
+use std::{ops::RangeInclusive, path::PathBuf, str::FromStr, sync::Arc, time::Instant};
 use tokio::sync::Mutex;
 use utilities::make_periodic_tick;
 
@@ -123,6 +124,17 @@ impl EvmRpcApi for EvmRpcClient {
 			anyhow!("Getting {} transaction for tx hash {tx_hash} returned None", self.chain_name)
 		})
 	}
+
+	async fn call(
+		&self,
+		req: &Eip1559TransactionRequest,
+		block: Option<BlockNumber>,
+	) -> Result<Bytes> {
+		Ok(self
+			.provider
+			.call(&TypedTransaction::Eip1559(req.clone()), block.map(Into::into))
+			.await?)
+	}
 }
 
 #[derive(Clone)]
@@ -217,6 +229,14 @@ pub trait EvmRpcApi: Send + Sync + Clone + 'static {
 	) -> Result<FeeHistory>;
 
 	async fn get_transaction(&self, tx_hash: H256) -> Result<Transaction>;
+
+	/// Executes a call against the state at `block`, or the latest block if `None`, without
+	/// creating a transaction, returning the output of the call.
+	async fn call(
+		&self,
+		req: &Eip1559TransactionRequest,
+		block: Option<BlockNumber>,
+	) -> Result<Bytes>;
 }
 
 #[async_trait::async_trait]
@@ -226,6 +246,82 @@ pub trait EvmSigningRpcApi: EvmRpcApi {
 	async fn send_transaction(&self, tx: Eip1559TransactionRequest) -> Result<TxHash>;
 }
 
+/// The maximum number of chunks of a log range that are requested at the same time.
+const MAX_CONCURRENT_LOG_REQUESTS: usize = 4;
+
+/// Gets the logs of a contract over a range of blocks, splitting the range into chunks of at most
+/// `chunk_size` blocks to stay within the limits of the node. A chunk that still fails is halved
+/// until it succeeds or is a single block, in which case the error is returned. The logs are
+/// returned in block order.
+pub async fn get_logs_chunked<Rpc: EvmRpcApi>(
+	client: &Rpc,
+	range: RangeInclusive<u64>,
+	contract_address: H160,
+	chunk_size: u64,
+) -> Result<Vec<Log>> {
+	get_logs_in_chunks(range, chunk_size, |range| {
+		client.get_logs(
+			// The `from_block` and `to_block` are inclusive
+			Filter::new()
+				.address(contract_address)
+				.from_block(*range.start())
+				.to_block(*range.end()),
+		)
+	})
+	.await
+}
+
+async fn get_logs_in_chunks<GetLogs, Fut>(
+	range: RangeInclusive<u64>,
+	chunk_size: u64,
+	get_logs: GetLogs,
+) -> Result<Vec<Log>>
+where
+	GetLogs: Fn(RangeInclusive<u64>) -> Fut,
+	Fut: Future<Output = Result<Vec<Log>>>,
+{
+	assert!(chunk_size > 0);
+
+	let end = *range.end();
+	futures::stream::iter(
+		range
+			.step_by(chunk_size as usize)
+			.map(|start| start..=start.saturating_add(chunk_size - 1).min(end)),
+	)
+	.map(|chunk| get_logs_halving_on_error(chunk, &get_logs))
+	.buffered(MAX_CONCURRENT_LOG_REQUESTS)
+	.try_concat()
+	.await
+}
+
+async fn get_logs_halving_on_error<GetLogs, Fut>(
+	range: RangeInclusive<u64>,
+	get_logs: &GetLogs,
+) -> Result<Vec<Log>>
+where
+	GetLogs: Fn(RangeInclusive<u64>) -> Fut,
+	Fut: Future<Output = Result<Vec<Log>>>,
+{
+	let mut logs = Vec::new();
+	let mut pending_ranges = vec![range];
+
+	while let Some(range) = pending_ranges.pop() {
+		match get_logs(range.clone()).await {
+			Ok(range_logs) => logs.extend(range_logs),
+			Err(e) if range.start() < range.end() => {
+				tracing::debug!("Failed to get logs for blocks {range:?}, halving the range: {e}");
+				let mid = range.start() + (range.end() - range.start()) / 2;
+				// Push the lower half last so it's requested first, keeping the logs in order.
+				pending_ranges.push(mid + 1..=*range.end());
+				pending_ranges.push(*range.start()..=mid);
+			},
+			Err(e) => return Err(e),
+		}
+	}
+
+	Ok(logs)
+}
+
 #[async_trait::async_trait]
 impl EvmRpcApi for EvmRpcSigningClient {
 	async fn estimate_gas(&self, req: &Eip1559TransactionRequest) -> Result<U256> {
@@ -267,6 +363,14 @@ impl EvmRpcApi for EvmRpcSigningClient {
 	async fn get_transaction(&self, tx_hash: H256) -> Result<Transaction> {
 		self.rpc_client.get_transaction(tx_hash).await
 	}
+
+	async fn call(
+		&self,
+		req: &Eip1559TransactionRequest,
+		block: Option<BlockNumber>,
+	) -> Result<Bytes> {
+		self.rpc_client.call(req, block).await
+	}
 }
 
 #[async_trait::async_trait]
@@ -350,6 +454,50 @@ impl ReconnectSubscribeApi for ReconnectSubscriptionClient {
 	}
 }
 
+#[cfg(test)]
+pub mod mocks {
+	use super::*;
+	use mockall::mock;
+
+	mock! {
+		pub EvmRpcClient {}
+
+		impl Clone for EvmRpcClient {
+			fn clone(&self) -> Self;
+		}
+
+		#[async_trait::async_trait]
+		impl EvmRpcApi for EvmRpcClient {
+			async fn estimate_gas(&self, req: &Eip1559TransactionRequest) -> Result<U256>;
+
+			async fn get_logs(&self, filter: Filter) -> Result<Vec<Log>>;
+
+			async fn chain_id(&self) -> Result<U256>;
+
+			async fn transaction_receipt(&self, tx_hash: H256) -> Result<TransactionReceipt>;
+
+			async fn block(&self, block_number: U64) -> Result<Block<H256>>;
+
+			async fn block_with_txs(&self, block_number: U64) -> Result<Block<Transaction>>;
+
+			async fn fee_history(
+				&self,
+				block_count: U256,
+				newest_block: BlockNumber,
+				reward_percentiles: &[f64],
+			) -> Result<FeeHistory>;
+
+			async fn get_transaction(&self, tx_hash: H256) -> Result<Transaction>;
+
+			async fn call(
+				&self,
+				req: &Eip1559TransactionRequest,
+				block: Option<BlockNumber>,
+			) -> Result<Bytes>;
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -357,6 +505,41 @@ mod tests {
 
 	use super::*;
 
+	#[tokio::test]
+	async fn get_logs_in_chunks_halves_ranges_that_are_too_large() {
+		const MAX_BLOCKS_PER_REQUEST: u64 = 3;
+
+		// Returns one log per block, failing if more than `MAX_BLOCKS_PER_REQUEST` are requested.
+		let get_logs = |range: RangeInclusive<u64>| async move {
+			if range.end() - range.start() + 1 > MAX_BLOCKS_PER_REQUEST {
+				Err(anyhow!("Too many blocks requested: {range:?}"))
+			} else {
+				Ok(range
+					.map(|block| Log { block_number: Some(block.into()), ..Default::default() })
+					.collect::<Vec<_>>())
+			}
+		};
+		let block_numbers = |logs: Vec<Log>| {
+			logs.into_iter()
+				.map(|log| log.block_number.unwrap().as_u64())
+				.collect::<Vec<_>>()
+		};
+
+		for chunk_size in [1, 3, 10, 100] {
+			assert_eq!(
+				block_numbers(get_logs_in_chunks(5..=24, chunk_size, get_logs).await.unwrap()),
+				(5..=24).collect::<Vec<_>>()
+			);
+		}
+
+		// Single blocks that fail can't be halved any further.
+		assert!(get_logs_in_chunks(5..=24, 10, |_range| async {
+			Err::<Vec<Log>, _>(anyhow!("Unavailable"))
+		})
+		.await
+		.is_err());
+	}
+
 	#[tokio::test]
 	#[ignore = "Requires correct settings"]
 	async fn eth_rpc_test() {
@@ -384,5 +567,8 @@ mod tests {
 			.await
 			.unwrap();
 		println!("{:?}", fee_history);
+
+		let output = client.call(&Eip1559TransactionRequest::default(), None).await.unwrap();
+		println!("{:?}", output);
 	}
 }
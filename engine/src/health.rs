@@ -1,10 +1,18 @@
 //! Health monitor for the CFE
 //! allowing external services to query, ensuring it's online
 //! Returns a HTTP 200 response to any request on {hostname}:{port}/health
+//! Returns a HTTP 200 response on {hostname}:{port}/liveness if all subsystems have made progress
+//! recently, and a HTTP 503 response naming the stalled subsystems otherwise.
 //! Method returns a Sender, allowing graceful termination of the infinite loop
 
-use std::{net::IpAddr, sync::Arc};
+use std::{
+	collections::BTreeMap,
+	net::IpAddr,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
 
+use serde::Serialize;
 use tracing::info;
 use utilities::task_scope;
 use warp::Filter;
@@ -14,27 +22,98 @@ use crate::settings;
 const INITIALISING: &str = "INITIALISING";
 const RUNNING: &str = "RUNNING";
 
+/// How long a subsystem may go without making progress before it is considered stalled, unless
+/// configured otherwise.
+const DEFAULT_MAX_SUBSYSTEM_STALENESS: Duration = Duration::from_secs(120);
+
+/// Tracks when each subsystem of the engine last made progress. Subsystems that have not made
+/// progress within the configured staleness threshold are reported as stalled.
+///
+/// The multisig clients are not tracked: if one of them fails, the engine exits.
+#[derive(Clone, Default)]
+pub struct SubsystemLiveness {
+	last_progress: Arc<Mutex<BTreeMap<&'static str, Instant>>>,
+}
+
+impl SubsystemLiveness {
+	/// Registers a subsystem, and returns a reporter the subsystem should use each time it makes
+	/// progress. The subsystem counts as having made progress at the time it is registered.
+	pub fn reporter(&self, subsystem: &'static str) -> LivenessReporter {
+		let reporter = LivenessReporter { subsystem, liveness: self.clone() };
+		reporter.report_progress();
+		reporter
+	}
+
+	fn stalled_subsystems(&self, max_staleness: Duration) -> Vec<&'static str> {
+		self.last_progress
+			.lock()
+			.unwrap()
+			.iter()
+			.filter(|(_, last_progress)| last_progress.elapsed() > max_staleness)
+			.map(|(subsystem, _)| *subsystem)
+			.collect()
+	}
+}
+
+#[derive(Clone)]
+pub struct LivenessReporter {
+	subsystem: &'static str,
+	liveness: SubsystemLiveness,
+}
+
+impl LivenessReporter {
+	pub fn report_progress(&self) {
+		self.liveness
+			.last_progress
+			.lock()
+			.unwrap()
+			.insert(self.subsystem, Instant::now());
+	}
+}
+
+#[derive(Serialize)]
+struct LivenessResponse {
+	stalled: Vec<&'static str>,
+}
+
 #[tracing::instrument(name = "health-check", skip_all)]
 pub async fn start<'a, 'env>(
 	scope: &'a task_scope::Scope<'env, anyhow::Error>,
 	health_check_settings: &'a settings::HealthCheck,
 	has_completed_initialising: Arc<std::sync::atomic::AtomicBool>,
+	subsystem_liveness: SubsystemLiveness,
 ) -> Result<(), anyhow::Error> {
 	info!("Starting");
 
 	const PATH: &str = "health";
+	const LIVENESS_PATH: &str = "liveness";
+
+	let max_subsystem_staleness = health_check_settings
+		.max_subsystem_staleness_secs
+		.map_or(DEFAULT_MAX_SUBSYSTEM_STALENESS, Duration::from_secs);
+
+	let health = warp::path(PATH).and(warp::path::end()).map(move || {
+		warp::reply::with_status(
+			if has_completed_initialising.load(std::sync::atomic::Ordering::Relaxed) {
+				RUNNING
+			} else {
+				INITIALISING
+			},
+			warp::http::StatusCode::OK,
+		)
+	});
 
-	let future =
-		warp::serve(warp::any().and(warp::path(PATH)).and(warp::path::end()).map(move || {
-			warp::reply::with_status(
-				if has_completed_initialising.load(std::sync::atomic::Ordering::Relaxed) {
-					RUNNING
-				} else {
-					INITIALISING
-				},
-				warp::http::StatusCode::OK,
-			)
-		}))
+	let liveness = warp::path(LIVENESS_PATH).and(warp::path::end()).map(move || {
+		let stalled = subsystem_liveness.stalled_subsystems(max_subsystem_staleness);
+		let status = if stalled.is_empty() {
+			warp::http::StatusCode::OK
+		} else {
+			warp::http::StatusCode::SERVICE_UNAVAILABLE
+		};
+		warp::reply::with_status(warp::reply::json(&LivenessResponse { stalled }), status)
+	});
+
+	let future = warp::serve(warp::any().and(health.or(liveness)))
 		.bind((health_check_settings.hostname.parse::<IpAddr>()?, health_check_settings.port));
 
 	scope.spawn_weak(async move {
@@ -62,7 +141,14 @@ mod tests {
 			async {
 				let has_completed_initialising =
 					Arc::new(std::sync::atomic::AtomicBool::new(false));
-				start(scope, &health_check, has_completed_initialising.clone()).await.unwrap();
+				start(
+					scope,
+					&health_check,
+					has_completed_initialising.clone(),
+					SubsystemLiveness::default(),
+				)
+				.await
+				.unwrap();
 
 				let request_test = |path: &'static str,
 				                    expected_status: reqwest::StatusCode,
@@ -97,4 +183,72 @@ mod tests {
 		.await
 		.unwrap();
 	}
+
+	#[tokio::test]
+	async fn liveness_check_reports_stalled_subsystems() {
+		let test_health_check = Settings::new_test().unwrap().health_check.unwrap();
+		// Use a different port so this test can run in parallel with the one above.
+		let health_check = settings::HealthCheck {
+			port: test_health_check.port + 1,
+			max_subsystem_staleness_secs: Some(60),
+			..test_health_check
+		};
+
+		task_scope::task_scope(|scope| {
+			async {
+				let subsystem_liveness = SubsystemLiveness::default();
+				let _state_chain_observer = subsystem_liveness.reporter("state_chain_observer");
+				let ethereum_rpc = subsystem_liveness.reporter("ethereum_rpc");
+				start(
+					scope,
+					&health_check,
+					Arc::new(std::sync::atomic::AtomicBool::new(true)),
+					subsystem_liveness.clone(),
+				)
+				.await
+				.unwrap();
+
+				let request_test = |expected_status: reqwest::StatusCode,
+				                    expected_text: &'static str| {
+					let health_check = health_check.clone();
+
+					async move {
+						let resp = reqwest::get(&format!(
+							"http://{}:{}/liveness",
+							&health_check.hostname, &health_check.port
+						))
+						.await
+						.unwrap();
+
+						assert_eq!(expected_status, resp.status());
+						assert_eq!(resp.text().await.unwrap(), expected_text);
+					}
+				};
+
+				request_test(reqwest::StatusCode::OK, r#"{"stalled":[]}"#).await;
+
+				// Simulate the Ethereum rpc not having produced a block for longer than allowed.
+				subsystem_liveness
+					.last_progress
+					.lock()
+					.unwrap()
+					.insert("ethereum_rpc", Instant::now() - Duration::from_secs(61));
+
+				request_test(
+					reqwest::StatusCode::SERVICE_UNAVAILABLE,
+					r#"{"stalled":["ethereum_rpc"]}"#,
+				)
+				.await;
+
+				ethereum_rpc.report_progress();
+
+				request_test(reqwest::StatusCode::OK, r#"{"stalled":[]}"#).await;
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
 }
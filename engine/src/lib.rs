@@ -118,6 +118,7 @@ async fn run_main(
 	task_scope(|scope| {
 		async move {
 			let has_completed_initialising = Arc::new(AtomicBool::new(false));
+			let subsystem_liveness = health::SubsystemLiveness::default();
 
 			let (state_chain_stream, unfinalised_state_chain_stream, state_chain_client) =
 				state_chain_observer::client::StateChainClient::connect_with_account(
@@ -136,21 +137,30 @@ async fn run_main(
 			tokio::time::sleep(Duration::from_secs(4)).await;
 
 			if let Some(health_check_settings) = &settings.health_check {
-				health::start(scope, health_check_settings, has_completed_initialising.clone())
-					.await?;
+				health::start(
+					scope,
+					health_check_settings,
+					has_completed_initialising.clone(),
+					subsystem_liveness.clone(),
+				)
+				.await?;
 			}
 
 			if let Some(prometheus_settings) = &settings.prometheus {
 				metrics::start(scope, prometheus_settings).await?;
 			}
 
-			let db = Arc::new(
-				PersistentKeyDB::open_and_migrate_to_latest(
-					&settings.signing.db_file,
-					Some(state_chain_client.genesis_hash()),
-				)
-				.context("Failed to open database")?,
-			);
+			let db = PersistentKeyDB::open_and_migrate_to_latest(
+				&settings.signing.db_file,
+				Some(state_chain_client.genesis_hash()),
+			)
+			.context("Failed to open database")?;
+			let db = Arc::new(match &settings.signing.key_share_passphrase {
+				Some(passphrase) => db
+					.with_key_share_encryption(passphrase)
+					.context("Failed to enable key share encryption")?,
+				None => db,
+			});
 
 			let (
 				eth_outgoing_sender,
@@ -187,6 +197,7 @@ async fn run_main(
 					eth_incoming_receiver,
 					eth_outgoing_sender,
 					ceremony_id_counters.ethereum,
+					settings.signing.ceremony_timeouts(),
 				);
 
 			scope.spawn(eth_multisig_client_backend_future);
@@ -198,6 +209,7 @@ async fn run_main(
 					dot_incoming_receiver,
 					dot_outgoing_sender,
 					ceremony_id_counters.polkadot,
+					settings.signing.ceremony_timeouts(),
 				);
 
 			scope.spawn(dot_multisig_client_backend_future);
@@ -209,6 +221,7 @@ async fn run_main(
 					btc_incoming_receiver,
 					btc_outgoing_sender,
 					ceremony_id_counters.bitcoin,
+					settings.signing.ceremony_timeouts(),
 				);
 
 			scope.spawn(btc_multisig_client_backend_future);
@@ -232,6 +245,7 @@ async fn run_main(
 					"eth_subscribe",
 					"Ethereum",
 					cf_chains::Ethereum::WITNESS_PERIOD,
+					settings.eth.request_timeouts,
 				)?
 			};
 			let arb_client = {
@@ -252,6 +266,7 @@ async fn run_main(
 					"arb_subscribe",
 					"Arbitrum",
 					cf_chains::Arbitrum::WITNESS_PERIOD,
+					settings.arb.request_timeouts,
 				)?
 			};
 
@@ -288,6 +303,7 @@ async fn run_main(
 				state_chain_stream.clone(),
 				unfinalised_state_chain_stream.clone(),
 				db.clone(),
+				subsystem_liveness.reporter("ethereum_rpc"),
 			)
 			.await?;
 
@@ -301,6 +317,7 @@ async fn run_main(
 				eth_multisig_client,
 				dot_multisig_client,
 				btc_multisig_client,
+				subsystem_liveness.reporter("state_chain_observer"),
 			));
 
 			p2p_ready_receiver.await.unwrap();
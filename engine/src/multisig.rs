@@ -1,7 +1,7 @@
 use anyhow::Result;
 use cf_primitives::CeremonyId;
 
-use multisig::{ChainSigning, MultisigClient};
+use multisig::{client::ceremony_manager::CeremonyTimeouts, ChainSigning, MultisigClient};
 use tracing::{info, info_span, Instrument};
 
 use crate::{
@@ -17,6 +17,7 @@ pub fn start_client<C: ChainSigning>(
 	incoming_p2p_message_receiver: MultisigMessageReceiver<<C as ChainSigning>::ChainCrypto>,
 	outgoing_p2p_message_sender: MultisigMessageSender<<C as ChainSigning>::ChainCrypto>,
 	latest_ceremony_id: CeremonyId,
+	ceremony_timeouts: CeremonyTimeouts,
 ) -> (MultisigClient<C, KeyStore<C>>, impl futures::Future<Output = Result<()>> + Send) {
 	info!("Starting {} MultisigClient", C::NAME);
 
@@ -33,6 +34,7 @@ pub fn start_client<C: ChainSigning>(
 			my_account_id,
 			outgoing_p2p_message_sender.0,
 			latest_ceremony_id,
+			ceremony_timeouts,
 		);
 
 		ceremony_manager
@@ -1,6 +1,7 @@
 mod core;
 mod muxer;
 mod peer_info_submitter;
+mod rate_limiter;
 
 use std::{
 	marker::PhantomData,
@@ -148,7 +149,14 @@ where
 		btc_outgoing_sender,
 		btc_incoming_receiver,
 		muxer_future,
-	) = P2PMuxer::start(incoming_message_receiver, outgoing_message_sender);
+	) = P2PMuxer::start(
+		incoming_message_receiver,
+		outgoing_message_sender,
+		rate_limiter::RateLimiter::new(
+			settings.max_incoming_messages_per_second,
+			settings.max_incoming_message_burst,
+		),
+	);
 
 	let fut = task_scope(move |scope| {
 		async move {
@@ -3,9 +3,12 @@ use cf_chains::{btc::BitcoinCrypto, dot::PolkadotCrypto, evm::EvmCrypto};
 use futures::Future;
 use state_chain_runtime::AccountId;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
-use tracing::{info_span, trace, warn, Instrument};
+use tracing::{debug, info_span, trace, warn, Instrument};
 
-use crate::p2p::{MultisigMessageReceiver, MultisigMessageSender, OutgoingMultisigStageMessages};
+use crate::p2p::{
+	rate_limiter::RateLimiter, MultisigMessageReceiver, MultisigMessageSender,
+	OutgoingMultisigStageMessages,
+};
 pub use multisig::p2p::{ProtocolVersion, VersionedCeremonyMessage, CURRENT_PROTOCOL_VERSION};
 use multisig::ChainTag;
 use utilities::metrics::P2P_BAD_MSG;
@@ -19,6 +22,7 @@ pub struct P2PMuxer {
 	dot_outgoing_receiver: UnboundedReceiver<OutgoingMultisigStageMessages>,
 	btc_incoming_sender: UnboundedSender<(AccountId, VersionedCeremonyMessage)>,
 	btc_outgoing_receiver: UnboundedReceiver<OutgoingMultisigStageMessages>,
+	incoming_rate_limiter: RateLimiter,
 }
 
 /// Top-level protocol message, encapsulates all others
@@ -86,6 +90,7 @@ impl P2PMuxer {
 	pub fn start(
 		all_incoming_receiver: UnboundedReceiver<(AccountId, Vec<u8>)>,
 		all_outgoing_sender: UnboundedSender<OutgoingMultisigStageMessages>,
+		incoming_rate_limiter: RateLimiter,
 	) -> (
 		MultisigMessageSender<EvmCrypto>,
 		MultisigMessageReceiver<EvmCrypto>,
@@ -113,6 +118,7 @@ impl P2PMuxer {
 			dot_incoming_sender,
 			btc_outgoing_receiver,
 			btc_incoming_sender,
+			incoming_rate_limiter,
 		};
 
 		let muxer_fut = muxer.run().instrument(info_span!("P2PMuxer"));
@@ -129,6 +135,12 @@ impl P2PMuxer {
 	}
 
 	async fn process_incoming(&mut self, account_id: AccountId, data: Vec<u8>) {
+		if !self.incoming_rate_limiter.try_acquire(&account_id, tokio::time::Instant::now()) {
+			P2P_BAD_MSG.inc(&["rate_limited"]);
+			debug!("Dropping p2p message from {account_id}: rate limit exceeded");
+			return
+		}
+
 		if let Ok(VersionedMessage { version, payload }) = VersionedMessage::deserialize(&data) {
 			// only version 1 is expected/supported
 			if version == CURRENT_PROTOCOL_VERSION {
@@ -228,6 +240,10 @@ mod tests {
 	const ETH_TAG_PREFIX: &[u8] = &ChainTag::Ethereum.to_bytes();
 	const VERSION_PREFIX: &[u8] = &CURRENT_PROTOCOL_VERSION.to_be_bytes();
 
+	fn unlimited_rate() -> RateLimiter {
+		RateLimiter::new(u32::MAX, u32::MAX)
+	}
+
 	#[tokio::test]
 	async fn correctly_prepends_chain_tag_broadcast() {
 		let (p2p_outgoing_sender, mut p2p_outgoing_receiver) =
@@ -235,7 +251,7 @@ mod tests {
 		let (_, p2p_incoming_receiver) = tokio::sync::mpsc::unbounded_channel();
 
 		let (eth_outgoing_sender, .., muxer_future) =
-			P2PMuxer::start(p2p_incoming_receiver, p2p_outgoing_sender);
+			P2PMuxer::start(p2p_incoming_receiver, p2p_outgoing_sender, unlimited_rate());
 
 		let _jh = tokio::task::spawn(muxer_future);
 
@@ -261,7 +277,7 @@ mod tests {
 		let (_, p2p_incoming_receiver) = tokio::sync::mpsc::unbounded_channel();
 
 		let (eth_outgoing_sender, .., muxer_future) =
-			P2PMuxer::start(p2p_incoming_receiver, p2p_outgoing_sender);
+			P2PMuxer::start(p2p_incoming_receiver, p2p_outgoing_sender, unlimited_rate());
 
 		let _jh = tokio::task::spawn(muxer_future);
 
@@ -300,7 +316,7 @@ mod tests {
 		let (p2p_incoming_sender, p2p_incoming_receiver) = tokio::sync::mpsc::unbounded_channel();
 
 		let (_eth_outgoing_sender, mut eth_incoming_receiver, .., muxer_future) =
-			P2PMuxer::start(p2p_incoming_receiver, p2p_outgoing_sender);
+			P2PMuxer::start(p2p_incoming_receiver, p2p_outgoing_sender, unlimited_rate());
 
 		tokio::spawn(muxer_future);
 
@@ -313,4 +329,33 @@ mod tests {
 		assert_eq!(received.0, ACC_1);
 		assert_eq!(received.1.payload, DATA_1.to_vec());
 	}
+
+	#[tokio::test]
+	async fn should_drop_messages_from_peers_exceeding_the_rate_limit() {
+		const BURST: u32 = 3;
+
+		let (p2p_outgoing_sender, _p2p_outgoing_receiver) = tokio::sync::mpsc::unbounded_channel();
+		let (p2p_incoming_sender, p2p_incoming_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+		// The refill rate is low enough that no tokens are refilled during the test.
+		let (_eth_outgoing_sender, mut eth_incoming_receiver, .., muxer_future) =
+			P2PMuxer::start(p2p_incoming_receiver, p2p_outgoing_sender, RateLimiter::new(1, BURST));
+
+		tokio::spawn(muxer_future);
+
+		let bytes = [VERSION_PREFIX, ETH_TAG_PREFIX, DATA_1].concat();
+
+		// ACC_1 floods us, ACC_2 only sends a single message afterwards.
+		for _ in 0..(BURST * 3) {
+			p2p_incoming_sender.send((ACC_1, bytes.clone())).unwrap();
+		}
+		p2p_incoming_sender.send((ACC_2, bytes)).unwrap();
+
+		// Messages are processed in order, so the flooding peer's excess messages must have been
+		// dropped if the well-behaved peer's message is received right after the burst.
+		for _ in 0..BURST {
+			assert_eq!(expect_recv_with_timeout(&mut eth_incoming_receiver.0).await.0, ACC_1);
+		}
+		assert_eq!(expect_recv_with_timeout(&mut eth_incoming_receiver.0).await.0, ACC_2);
+	}
 }
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use state_chain_runtime::AccountId;
+use tokio::time::Instant;
+
+struct TokenBucket {
+	tokens: f64,
+	last_refill: Instant,
+}
+
+/// Limits the rate of incoming messages from each peer using a token bucket per peer: every
+/// peer can send up to `burst` messages at once, after which messages are only accepted at
+/// `messages_per_second`. Only authenticated peers can send us messages, so the number of
+/// buckets is bounded by the number of registered peers.
+pub struct RateLimiter {
+	messages_per_second: f64,
+	burst: f64,
+	buckets: HashMap<AccountId, TokenBucket>,
+}
+
+impl RateLimiter {
+	pub fn new(messages_per_second: u32, burst: u32) -> Self {
+		Self {
+			messages_per_second: messages_per_second as f64,
+			burst: burst as f64,
+			buckets: Default::default(),
+		}
+	}
+
+	/// Returns whether a message received from `account_id` at `now` is within the rate limit,
+	/// consuming a token if it is.
+	pub fn try_acquire(&mut self, account_id: &AccountId, now: Instant) -> bool {
+		let bucket = self
+			.buckets
+			.entry(account_id.clone())
+			.or_insert(TokenBucket { tokens: self.burst, last_refill: now });
+
+		let elapsed = now.saturating_duration_since(bucket.last_refill).as_secs_f64();
+		bucket.tokens = (bucket.tokens + elapsed * self.messages_per_second).min(self.burst);
+		bucket.last_refill = now;
+
+		if bucket.tokens >= 1.0 {
+			bucket.tokens -= 1.0;
+			true
+		} else {
+			false
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::*;
+
+	const ACC_1: AccountId = AccountId::new([b'A'; 32]);
+	const ACC_2: AccountId = AccountId::new([b'B'; 32]);
+
+	#[test]
+	fn limits_each_peer_independently_and_refills_over_time() {
+		let mut rate_limiter = RateLimiter::new(2, 3);
+		let start = Instant::now();
+
+		// The full burst is accepted, but nothing more.
+		for _ in 0..3 {
+			assert!(rate_limiter.try_acquire(&ACC_1, start));
+		}
+		assert!(!rate_limiter.try_acquire(&ACC_1, start));
+
+		// Other peers are unaffected.
+		assert!(rate_limiter.try_acquire(&ACC_2, start));
+
+		// Tokens are refilled at the configured rate.
+		let later = start + Duration::from_millis(500);
+		assert!(rate_limiter.try_acquire(&ACC_1, later));
+		assert!(!rate_limiter.try_acquire(&ACC_1, later));
+
+		// ...but never beyond the burst size.
+		let much_later = later + Duration::from_secs(60);
+		for _ in 0..3 {
+			assert!(rate_limiter.try_acquire(&ACC_1, much_later));
+		}
+		assert!(!rate_limiter.try_acquire(&ACC_1, much_later));
+	}
+}
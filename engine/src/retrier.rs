@@ -114,10 +114,10 @@ type RetryDelays = FuturesUnordered<
 
 type BoxAny = Box<dyn Any + Send>;
 
-type RequestPackage<Client> = (oneshot::Sender<BoxAny>, FutureAnyGenerator<Client>);
+type RequestPackage<Client> = (oneshot::Sender<BoxAny>, FutureAnyGenerator<Client>, Duration);
 
 type RequestSent<Client> =
-	(oneshot::Sender<BoxAny>, RequestLog, FutureAnyGenerator<Client>, RetryLimit);
+	(oneshot::Sender<BoxAny>, RequestLog, FutureAnyGenerator<Client>, RetryLimit, Option<Duration>);
 
 /// Tracks all the retries
 #[derive(Clone)]
@@ -377,27 +377,32 @@ where
 
 		scope.spawn(async move {
 			utilities::loop_select! {
-				if let Some((response_sender, request_log, closure, retry_limit)) = request_receiver.recv() => {
+				if let Some((response_sender, request_log, closure, retry_limit, request_timeout)) = request_receiver.recv() => {
 					RPC_RETRIER_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
 					let request_id = request_holder.next_request_id();
 					let (client, primary_or_secondary) = client_selector.select_client(PrimaryOrSecondary::Primary).await;
+					let request_timeout = request_timeout.unwrap_or(initial_request_timeout);
 
 					tracing::debug!("Retrier {name}: Received request `{request_log}` assigning request_id `{request_id}` and requesting with `{primary_or_secondary:?}`");
-					submission_holder.push(submission_future(client, request_log, retry_limit, &closure, request_id, initial_request_timeout, 0, primary_or_secondary));
-					request_holder.insert(request_id, (response_sender, closure));
+					submission_holder.push(submission_future(client, request_log, retry_limit, &closure, request_id, request_timeout, 0, primary_or_secondary));
+					request_holder.insert(request_id, (response_sender, closure, request_timeout));
 				},
 				let (request_id, request_log, retry_limit, primary_or_secondary, result) = submission_holder.next_or_pending() => {
 					RPC_RETRIER_TOTAL_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
 					match result {
 						Ok(value) => {
-							if let Some((response_sender, _)) = request_holder.remove(&request_id) {
+							if let Some((response_sender, ..)) = request_holder.remove(&request_id) {
 								let _result = response_sender.send(value);
 							}
 						},
 						Err((e, attempt)) => {
+							let request_timeout = request_holder
+								.get(&request_id)
+								.map_or(initial_request_timeout, |(.., request_timeout)| *request_timeout);
+
 							// Apply exponential back off with jitter to the retries.
 							// We avoid small delays by always having a time of at least half.
-							let half_max = max_sleep_duration(initial_request_timeout, attempt) / 2;
+							let half_max = max_sleep_duration(request_timeout, attempt) / 2;
 							let sleep_duration = half_max + rand::thread_rng().gen_range(Duration::default()..half_max);
 
 							let error_message = format!("Retrier {name}: Error for request `{request_log}` with id `{request_id}`, attempt `{attempt}`: {e}. Delaying for {:?}", sleep_duration);
@@ -421,7 +426,7 @@ where
 				let (request_id, request_log, attempt, retry_limit, primary_or_secondary) = retry_delays.next_or_pending() => {
 					let next_attempt = attempt.saturating_add(1);
 
-					let (response_sender, closure) = request_holder.get(&request_id).expect("We only remove these on success, and if it's in `retry_delays` then it must still be in `request_holder`");
+					let (response_sender, closure, request_timeout) = request_holder.get(&request_id).expect("We only remove these on success, and if it's in `retry_delays` then it must still be in `request_holder`");
 
 					if response_sender.is_closed() {
 						tracing::trace!("Retrier {name}: Dropped request `{request_log}` with id `{request_id}`. Not retrying.");
@@ -437,7 +442,7 @@ where
 								// This await should always return immediately since we must already have a client if we've already made a request.
 								let (next_client, next_primary_or_secondary) = client_selector.select_client(!primary_or_secondary).await;
 								tracing::trace!("Retrier {name}: Retrying request `{request_log}` with id `{request_id}` and client `{next_primary_or_secondary:?}`, attempt `{next_attempt}`");
-								submission_holder.push(submission_future(next_client, request_log, retry_limit, closure, request_id, initial_request_timeout, next_attempt, next_primary_or_secondary));
+								submission_holder.push(submission_future(next_client, request_log, retry_limit, closure, request_id, *request_timeout, next_attempt, next_primary_or_secondary));
 							}
 						}
 					}
@@ -455,6 +460,7 @@ where
 		specific_closure: TypedFutureGenerator<T, Client>,
 		request_log: RequestLog,
 		retry_limit: RetryLimit,
+		request_timeout: Option<Duration>,
 	) -> oneshot::Receiver<BoxAny> {
 		let future_any_fn: FutureAnyGenerator<Client> = Box::pin(move |client| {
 			let future = specific_closure(client);
@@ -465,7 +471,10 @@ where
 			})
 		});
 		let (tx, rx) = oneshot::channel::<BoxAny>();
-		let _result = self.request_sender.send((tx, request_log, future_any_fn, retry_limit)).await;
+		let _result = self
+			.request_sender
+			.send((tx, request_log, future_any_fn, retry_limit, request_timeout))
+			.await;
 		rx
 	}
 
@@ -487,9 +496,45 @@ where
 		request_log: RequestLog,
 		specific_closure: TypedFutureGenerator<T, Client>,
 		retry_limit: R,
+	) -> R::ReturnType<T> {
+		self.request_with_optional_timeout(request_log, specific_closure, retry_limit, None)
+			.await
+	}
+
+	/// Requests something to be retried by the retry client, with an explicit retry limit and an
+	/// initial timeout that overrides the one the client was created with.
+	pub async fn request_with_timeout<T: Send + 'static, R: RetryLimitReturn>(
+		&self,
+		request_log: RequestLog,
+		specific_closure: TypedFutureGenerator<T, Client>,
+		retry_limit: R,
+		initial_request_timeout: Duration,
+	) -> R::ReturnType<T> {
+		self.request_with_optional_timeout(
+			request_log,
+			specific_closure,
+			retry_limit,
+			Some(initial_request_timeout),
+		)
+		.await
+	}
+
+	async fn request_with_optional_timeout<T: Send + 'static, R: RetryLimitReturn>(
+		&self,
+		request_log: RequestLog,
+		specific_closure: TypedFutureGenerator<T, Client>,
+		retry_limit: R,
+		initial_request_timeout: Option<Duration>,
 	) -> R::ReturnType<T> {
 		let retry_limit = R::into_retry_limit(retry_limit);
-		let rx = self.send_request(specific_closure, request_log.clone(), retry_limit).await;
+		let rx = self
+			.send_request(
+				specific_closure,
+				request_log.clone(),
+				retry_limit,
+				initial_request_timeout,
+			)
+			.await;
 		R::inner_to_return_type(
 			rx.await,
 			format!("Maximum attempt of `{retry_limit:?}` reached for request `{request_log}`."),
@@ -548,6 +593,7 @@ mod tests {
 						specific_fut_closure(REQUEST_1, INITIAL_TIMEOUT),
 						RequestLog::new("request 1".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -557,6 +603,7 @@ mod tests {
 						specific_fut_closure(REQUEST_2, INITIAL_TIMEOUT),
 						RequestLog::new("request 2".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -566,6 +613,7 @@ mod tests {
 						specific_fut_closure(REQUEST_3, INITIAL_TIMEOUT),
 						RequestLog::new("request 3".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -598,6 +646,7 @@ mod tests {
 						specific_fut_closure(REQUEST_1, TIMEOUT),
 						RequestLog::new("request 1".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -607,6 +656,7 @@ mod tests {
 						specific_fut_closure(REQUEST_2, TIMEOUT),
 						RequestLog::new("request 2".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -721,6 +771,7 @@ mod tests {
 						specific_fut_closure(REQUEST_1, TIMEOUT),
 						RequestLog::new("request 1".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -730,6 +781,7 @@ mod tests {
 						specific_fut_closure(REQUEST_2, TIMEOUT),
 						RequestLog::new("request 2".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -809,6 +861,59 @@ mod tests {
 		.unwrap();
 	}
 
+	#[tokio::test]
+	async fn request_timeout_overrides_the_client_timeout() {
+		task_scope(|scope| {
+			async move {
+				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
+				const REQUEST_DURATION: Duration = Duration::from_millis(300);
+
+				let retrier_client =
+					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+
+				// The request takes longer than the client's timeout...
+				retrier_client
+					.request_with_limit(
+						RequestLog::new("request".to_string(), None),
+						specific_fut_closure(1u32, REQUEST_DURATION),
+						1,
+					)
+					.await
+					.unwrap_err();
+
+				// ...and than a shorter per-request timeout...
+				retrier_client
+					.request_with_timeout(
+						RequestLog::new("request".to_string(), None),
+						specific_fut_closure(1u32, REQUEST_DURATION),
+						1,
+						Duration::from_millis(50),
+					)
+					.await
+					.unwrap_err();
+
+				// ...but succeeds within a longer one.
+				assert_eq!(
+					retrier_client
+						.request_with_timeout(
+							RequestLog::new("request".to_string(), None),
+							specific_fut_closure(1u32, REQUEST_DURATION),
+							1,
+							Duration::from_secs(1),
+						)
+						.await
+						.unwrap(),
+					1
+				);
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+
 	async fn get_client(ready: bool) {
 		if !ready {
 			futures::future::pending().await
@@ -4,6 +4,7 @@ use std::{
 	fmt,
 	net::IpAddr,
 	path::{Path, PathBuf},
+	time::Duration,
 };
 
 use anyhow::{bail, Context};
@@ -16,6 +17,8 @@ use sp_runtime::DeserializeOwned;
 use url::Url;
 
 use clap::Parser;
+use multisig::client::ceremony_manager::CeremonyTimeouts;
+use state_chain_runtime::constants::common::MAX_STAGE_DURATION_SECONDS;
 use utilities::{
 	logging::LoggingSettings, metrics::Prometheus, redact_endpoint_secret::SecretUrl, Port,
 };
@@ -31,6 +34,10 @@ pub struct P2P {
 	pub ip_address: IpAddr,
 	pub port: Port,
 	pub allow_local_ip: bool,
+	/// The sustained number of incoming messages per second accepted from any one peer.
+	pub max_incoming_messages_per_second: u32,
+	/// The number of incoming messages a peer can send in a burst before being rate limited.
+	pub max_incoming_message_burst: u32,
 }
 
 #[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
@@ -93,11 +100,60 @@ pub struct Evm {
 	pub nodes: NodeContainer<WsHttpEndpoints>,
 	#[serde(deserialize_with = "deser_path")]
 	pub private_key_file: PathBuf,
+	#[serde(default)]
+	pub request_timeouts: EvmRequestTimeouts,
+}
+
+/// Initial timeouts, in milliseconds, for each category of EVM rpc request. The timeouts are
+/// doubled on each retry.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(default)]
+pub struct EvmRequestTimeouts {
+	pub logs_ms: u64,
+	pub fee_history_ms: u64,
+	pub send_ms: u64,
+	pub read_ms: u64,
+}
+
+const DEFAULT_EVM_REQUEST_TIMEOUT_MS: u64 = 4_000;
+/// Shorter timeouts leave requests no realistic chance of completing, and a timeout of zero would
+/// leave the retrier no range to pick a back off delay from.
+const MIN_EVM_REQUEST_TIMEOUT_MS: u64 = 100;
+
+impl Default for EvmRequestTimeouts {
+	fn default() -> Self {
+		Self {
+			logs_ms: DEFAULT_EVM_REQUEST_TIMEOUT_MS,
+			fee_history_ms: DEFAULT_EVM_REQUEST_TIMEOUT_MS,
+			send_ms: DEFAULT_EVM_REQUEST_TIMEOUT_MS,
+			read_ms: DEFAULT_EVM_REQUEST_TIMEOUT_MS,
+		}
+	}
+}
+
+impl ValidateSettings for EvmRequestTimeouts {
+	/// Ensure none of the timeouts is too short to be usable.
+	fn validate(&self) -> Result<(), ConfigError> {
+		for (category, timeout_ms) in [
+			("logs_ms", self.logs_ms),
+			("fee_history_ms", self.fee_history_ms),
+			("send_ms", self.send_ms),
+			("read_ms", self.read_ms),
+		] {
+			if timeout_ms < MIN_EVM_REQUEST_TIMEOUT_MS {
+				return Err(ConfigError::Message(format!(
+					"request_timeouts.{category} must be at least {MIN_EVM_REQUEST_TIMEOUT_MS}ms"
+				)))
+			}
+		}
+		Ok(())
+	}
 }
 
 impl Evm {
 	pub fn validate_settings(&self) -> Result<(), ConfigError> {
-		self.nodes.validate()
+		self.nodes.validate()?;
+		self.request_timeouts.validate()
 	}
 }
 
@@ -172,12 +228,54 @@ impl Btc {
 pub struct HealthCheck {
 	pub hostname: String,
 	pub port: Port,
+	/// How long a subsystem may go without making progress before the liveness check fails.
+	pub max_subsystem_staleness_secs: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct Signing {
 	#[serde(deserialize_with = "deser_path")]
 	pub db_file: PathBuf,
+	/// If set, key shares are encrypted at rest using a key derived from this passphrase.
+	pub key_share_passphrase: Option<String>,
+	/// How long each stage of a keygen ceremony is given to complete. Defaults to the State
+	/// Chain's maximum stage duration, which it may not exceed.
+	pub keygen_stage_duration_secs: Option<u64>,
+	/// How long each stage of a signing ceremony is given to complete. Defaults to the State
+	/// Chain's maximum stage duration, which it may not exceed.
+	pub signing_stage_duration_secs: Option<u64>,
+}
+
+impl Signing {
+	pub fn validate_settings(&self) -> Result<(), ConfigError> {
+		for (setting, duration_secs) in [
+			(SIGNING_KEYGEN_STAGE_DURATION_SECS, self.keygen_stage_duration_secs),
+			(SIGNING_SIGNING_STAGE_DURATION_SECS, self.signing_stage_duration_secs),
+		] {
+			// The State Chain times out ceremonies assuming no stage takes longer than this, so a
+			// longer stage would outlive the ceremony itself.
+			if let Some(secs) = duration_secs {
+				if !(1..=MAX_STAGE_DURATION_SECONDS as u64).contains(&secs) {
+					return Err(ConfigError::Message(format!(
+						"{setting} must be between 1 and {MAX_STAGE_DURATION_SECONDS} seconds"
+					)))
+				}
+			}
+		}
+		Ok(())
+	}
+
+	pub fn ceremony_timeouts(&self) -> CeremonyTimeouts {
+		let defaults = CeremonyTimeouts::default();
+		CeremonyTimeouts {
+			keygen_stage_duration: self
+				.keygen_stage_duration_secs
+				.map_or(defaults.keygen_stage_duration, Duration::from_secs),
+			signing_stage_duration: self
+				.signing_stage_duration_secs
+				.map_or(defaults.signing_stage_duration, Duration::from_secs),
+		}
+	}
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
@@ -276,6 +374,10 @@ pub struct P2POptions {
 	p2p_port: Option<Port>,
 	#[clap(long = "p2p.allow_local_ip")]
 	allow_local_ip: Option<bool>,
+	#[clap(long = "p2p.max_incoming_messages_per_second")]
+	max_incoming_messages_per_second: Option<u32>,
+	#[clap(long = "p2p.max_incoming_message_burst")]
+	max_incoming_message_burst: Option<u32>,
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -308,6 +410,8 @@ pub struct CommandLineOptions {
 	pub health_check_hostname: Option<String>,
 	#[clap(long = "health_check.port")]
 	pub health_check_port: Option<Port>,
+	#[clap(long = "health_check.max_subsystem_staleness_secs")]
+	pub health_check_max_subsystem_staleness_secs: Option<u64>,
 
 	// Prometheus Settings
 	#[clap(long = "prometheus.hostname")]
@@ -318,6 +422,10 @@ pub struct CommandLineOptions {
 	// Signing Settings
 	#[clap(long = "signing.db_file", parse(from_os_str))]
 	pub signing_db_file: Option<PathBuf>,
+	#[clap(long = "signing.keygen_stage_duration_secs")]
+	pub signing_keygen_stage_duration_secs: Option<u64>,
+	#[clap(long = "signing.signing_stage_duration_secs")]
+	pub signing_signing_stage_duration_secs: Option<u64>,
 
 	// Logging settings
 	#[clap(long = "logging.span_lifecycle")]
@@ -342,9 +450,12 @@ impl Default for CommandLineOptions {
 			arb_opts: ArbOptions::default(),
 			health_check_hostname: None,
 			health_check_port: None,
+			health_check_max_subsystem_staleness_secs: None,
 			prometheus_hostname: None,
 			prometheus_port: None,
 			signing_db_file: None,
+			signing_keygen_stage_duration_secs: None,
+			signing_signing_stage_duration_secs: None,
 			logging_span_lifecycle: false,
 			logging_command_server_port: None,
 		}
@@ -354,6 +465,8 @@ impl Default for CommandLineOptions {
 const NODE_P2P_KEY_FILE: &str = "node_p2p.node_key_file";
 const NODE_P2P_PORT: &str = "node_p2p.port";
 const NODE_P2P_ALLOW_LOCAL_IP: &str = "node_p2p.allow_local_ip";
+const NODE_P2P_MAX_INCOMING_MESSAGES_PER_SECOND: &str = "node_p2p.max_incoming_messages_per_second";
+const NODE_P2P_MAX_INCOMING_MESSAGE_BURST: &str = "node_p2p.max_incoming_message_burst";
 
 const STATE_CHAIN_WS_ENDPOINT: &str = "state_chain.ws_endpoint";
 const STATE_CHAIN_SIGNING_KEY_FILE: &str = "state_chain.signing_key_file";
@@ -362,6 +475,8 @@ const ETH_PRIVATE_KEY_FILE: &str = "eth.private_key_file";
 const ARB_PRIVATE_KEY_FILE: &str = "arb.private_key_file";
 
 const SIGNING_DB_FILE: &str = "signing.db_file";
+const SIGNING_KEYGEN_STAGE_DURATION_SECS: &str = "signing.keygen_stage_duration_secs";
+const SIGNING_SIGNING_STAGE_DURATION_SECS: &str = "signing.signing_stage_duration_secs";
 
 const LOGGING_SPAN_LIFECYCLE: &str = "logging.span_lifecycle";
 const LOGGING_COMMAND_SERVER_PORT: &str = "logging.command_server_port";
@@ -526,6 +641,8 @@ impl CfSettings for Settings {
 
 		self.state_chain.validate_settings()?;
 
+		self.signing.validate_settings()?;
+
 		is_valid_db_path(&self.signing.db_file).map_err(|e| ConfigError::Message(e.to_string()))?;
 
 		self.state_chain.signing_key_file = resolve_settings_path(
@@ -569,6 +686,8 @@ impl CfSettings for Settings {
 					.expect("Invalid node_key_file path"),
 			)?
 			.set_default(NODE_P2P_PORT, 8078)?
+			.set_default(NODE_P2P_MAX_INCOMING_MESSAGES_PER_SECOND, 200)?
+			.set_default(NODE_P2P_MAX_INCOMING_MESSAGE_BURST, 2_000)?
 			.set_default(STATE_CHAIN_WS_ENDPOINT, "ws://localhost:9944")?
 			.set_default(
 				STATE_CHAIN_SIGNING_KEY_FILE,
@@ -623,11 +742,26 @@ impl Source for CommandLineOptions {
 
 		insert_command_line_option(&mut map, "health_check.hostname", &self.health_check_hostname);
 		insert_command_line_option(&mut map, "health_check.port", &self.health_check_port);
+		insert_command_line_option(
+			&mut map,
+			"health_check.max_subsystem_staleness_secs",
+			&self.health_check_max_subsystem_staleness_secs,
+		);
 
 		insert_command_line_option(&mut map, "prometheus.hostname", &self.prometheus_hostname);
 		insert_command_line_option(&mut map, "prometheus.port", &self.prometheus_port);
 
 		insert_command_line_option_path(&mut map, SIGNING_DB_FILE, &self.signing_db_file);
+		insert_command_line_option(
+			&mut map,
+			SIGNING_KEYGEN_STAGE_DURATION_SECS,
+			&self.signing_keygen_stage_duration_secs,
+		);
+		insert_command_line_option(
+			&mut map,
+			SIGNING_SIGNING_STAGE_DURATION_SECS,
+			&self.signing_signing_stage_duration_secs,
+		);
 		insert_command_line_option(
 			&mut map,
 			LOGGING_SPAN_LIFECYCLE,
@@ -709,6 +843,16 @@ impl P2POptions {
 		);
 		insert_command_line_option(map, NODE_P2P_PORT, &self.p2p_port);
 		insert_command_line_option(map, NODE_P2P_ALLOW_LOCAL_IP, &self.allow_local_ip);
+		insert_command_line_option(
+			map,
+			NODE_P2P_MAX_INCOMING_MESSAGES_PER_SECOND,
+			&self.max_incoming_messages_per_second,
+		);
+		insert_command_line_option(
+			map,
+			NODE_P2P_MAX_INCOMING_MESSAGE_BURST,
+			&self.max_incoming_message_burst,
+		);
 	}
 }
 
@@ -916,6 +1060,7 @@ pub mod tests {
 		assert_eq!(settings.state_chain.ws_endpoint, "ws://localhost:9944");
 		assert_eq!(settings.eth.nodes.primary.http_endpoint.as_ref(), "http://localhost:8545");
 		assert_eq!(settings.arb.nodes.primary.http_endpoint.as_ref(), "http://localhost:8547");
+		assert_eq!(settings.eth.request_timeouts, EvmRequestTimeouts::default());
 		assert_eq!(
 			settings.dot.nodes.primary.ws_endpoint.as_ref(),
 			"wss://my_fake_polkadot_rpc:443/<secret_key>"
@@ -988,6 +1133,8 @@ pub mod tests {
 				ip_address: Some("1.1.1.1".parse().unwrap()),
 				p2p_port: Some(8087),
 				allow_local_ip: Some(false),
+				max_incoming_messages_per_second: Some(50),
+				max_incoming_message_burst: Some(500),
 			},
 			state_chain_opts: StateChainOptions {
 				state_chain_ws_endpoint: Some("ws://endpoint:1234".to_owned()),
@@ -1027,9 +1174,12 @@ pub mod tests {
 			},
 			health_check_hostname: Some("health_check_hostname".to_owned()),
 			health_check_port: Some(1337),
+			health_check_max_subsystem_staleness_secs: Some(300),
 			prometheus_hostname: Some(("prometheus_hostname").to_owned()),
 			prometheus_port: Some(9999),
 			signing_db_file: Some(PathBuf::from_str("also/not/real.db").unwrap()),
+			signing_keygen_stage_duration_secs: Some(20),
+			signing_signing_stage_duration_secs: Some(10),
 			logging_span_lifecycle: true,
 			logging_command_server_port: Some(6969),
 		};
@@ -1044,6 +1194,14 @@ pub mod tests {
 		assert_eq!(opts.p2p_opts.p2p_port.unwrap(), settings.node_p2p.port);
 		assert_eq!(opts.p2p_opts.ip_address.unwrap(), settings.node_p2p.ip_address);
 		assert_eq!(opts.p2p_opts.allow_local_ip.unwrap(), settings.node_p2p.allow_local_ip);
+		assert_eq!(
+			opts.p2p_opts.max_incoming_messages_per_second.unwrap(),
+			settings.node_p2p.max_incoming_messages_per_second
+		);
+		assert_eq!(
+			opts.p2p_opts.max_incoming_message_burst.unwrap(),
+			settings.node_p2p.max_incoming_message_burst
+		);
 
 		assert_eq!(
 			opts.state_chain_opts.state_chain_ws_endpoint.unwrap(),
@@ -1148,6 +1306,10 @@ pub mod tests {
 			settings.health_check.as_ref().unwrap().hostname
 		);
 		assert_eq!(opts.health_check_port.unwrap(), settings.health_check.as_ref().unwrap().port);
+		assert_eq!(
+			opts.health_check_max_subsystem_staleness_secs,
+			settings.health_check.as_ref().unwrap().max_subsystem_staleness_secs
+		);
 
 		assert_eq!(
 			opts.prometheus_hostname.unwrap(),
@@ -1156,6 +1318,14 @@ pub mod tests {
 		assert_eq!(opts.prometheus_port.unwrap(), settings.prometheus.as_ref().unwrap().port);
 
 		assert!(settings.signing.db_file.ends_with("not/real.db"));
+		assert_eq!(
+			opts.signing_keygen_stage_duration_secs,
+			settings.signing.keygen_stage_duration_secs
+		);
+		assert_eq!(
+			opts.signing_signing_stage_duration_secs,
+			settings.signing.signing_stage_duration_secs
+		);
 	}
 
 	#[test]
@@ -1212,6 +1382,50 @@ pub mod tests {
 		assert!(is_valid_db_path(Path::new("thishasnoextension")).is_err());
 	}
 
+	#[test]
+	fn test_evm_request_timeouts_validation() {
+		assert_ok!(EvmRequestTimeouts::default().validate());
+		assert_ok!(EvmRequestTimeouts {
+			read_ms: MIN_EVM_REQUEST_TIMEOUT_MS,
+			..Default::default()
+		}
+		.validate());
+		assert!(EvmRequestTimeouts { logs_ms: 0, ..Default::default() }.validate().is_err());
+		assert!(EvmRequestTimeouts {
+			send_ms: MIN_EVM_REQUEST_TIMEOUT_MS - 1,
+			..Default::default()
+		}
+		.validate()
+		.is_err());
+	}
+
+	#[test]
+	fn test_ceremony_stage_duration_validation() {
+		let signing_settings = |keygen_stage_duration_secs, signing_stage_duration_secs| Signing {
+			db_file: PathBuf::from("data.db"),
+			key_share_passphrase: None,
+			keygen_stage_duration_secs,
+			signing_stage_duration_secs,
+		};
+		let max = MAX_STAGE_DURATION_SECONDS as u64;
+
+		assert_ok!(signing_settings(None, None).validate_settings());
+		assert_ok!(signing_settings(Some(1), Some(max)).validate_settings());
+		assert!(signing_settings(Some(0), None).validate_settings().is_err());
+		assert!(signing_settings(None, Some(0)).validate_settings().is_err());
+		assert!(signing_settings(Some(max + 1), None).validate_settings().is_err());
+		assert!(signing_settings(None, Some(max + 1)).validate_settings().is_err());
+
+		assert_eq!(signing_settings(None, None).ceremony_timeouts(), CeremonyTimeouts::default());
+		assert_eq!(
+			signing_settings(Some(5), Some(10)).ceremony_timeouts(),
+			CeremonyTimeouts {
+				keygen_stage_duration: Duration::from_secs(5),
+				signing_stage_duration: Duration::from_secs(10),
+			}
+		);
+	}
+
 	#[test]
 	fn test_dot_port_validation() {
 		let valid_settings = Dot {
@@ -30,6 +30,7 @@ use crate::{
 	btc::retry_rpc::BtcRetryRpcApi,
 	dot::retry_rpc::DotRetryRpcApi,
 	evm::retry_rpc::EvmRetrySigningRpcApi,
+	health::LivenessReporter,
 	state_chain_observer::client::{
 		extrinsic_api::{
 			signed::{SignedExtrinsicApi, UntilFinalized},
@@ -235,6 +236,7 @@ pub async fn start<
 	eth_multisig_client: EthMultisigClient,
 	dot_multisig_client: PolkadotMultisigClient,
 	btc_multisig_client: BitcoinMultisigClient,
+	liveness: LivenessReporter,
 ) -> Result<(), anyhow::Error>
 where
 	BlockStream: StreamApi<FINALIZED>,
@@ -557,6 +559,8 @@ where
                         }
                     }
 
+                    liveness.report_progress();
+
                     // All nodes must send a heartbeat regardless of their validator status (at least for now).
                     // We send it every `blocks_per_heartbeat` from the block they started up at.
                     if ((current_block.number - last_heartbeat_submitted_at) >= blocks_per_heartbeat
@@ -4,6 +4,7 @@ use crate::{
 	btc::retry_rpc::mocks::MockBtcRetryRpcClient,
 	dot::retry_rpc::mocks::MockDotHttpRpcClient,
 	evm::retry_rpc::mocks::MockEvmRetryRpcClient,
+	health::SubsystemLiveness,
 	state_chain_observer::{
 		client::{
 			extrinsic_api,
@@ -58,6 +59,7 @@ async fn start_sc_observer<
 		MockMultisigClientApi::new(),
 		MockMultisigClientApi::new(),
 		MockMultisigClientApi::new(),
+		SubsystemLiveness::default().reporter("state_chain_observer"),
 	)
 	.await
 	.unwrap_err();
@@ -731,6 +733,7 @@ async fn run_the_sc_observer() {
 				MockMultisigClientApi::new(),
 				MockMultisigClientApi::new(),
 				MockMultisigClientApi::new(),
+				SubsystemLiveness::default().reporter("state_chain_observer"),
 			)
 			.await
 			.unwrap_err();
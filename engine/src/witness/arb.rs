@@ -252,6 +252,7 @@ mod tests {
 						"arb_subscribe",
 						"Arbitrum",
 						Arbitrum::WITNESS_PERIOD,
+						Default::default(),
 					).unwrap()
 				};
 
@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
-use cf_chains::{instances::ChainInstanceFor, ChainState};
+use cf_chains::{instances::ChainInstanceFor, Chain, ChainState};
 use frame_support::CloneNoBound;
 use futures::Future;
 use futures_util::{stream, StreamExt};
 use tokio::sync::watch;
 use utilities::{
 	loop_select,
+	metrics::{WITNESS_PENDING_HEADERS, WITNESS_READY_HEADERS},
 	task_scope::{Scope, UnwrapOrCancel},
 };
 
@@ -212,6 +213,11 @@ where
 								self.pending_headers.push(header);
 							}
 						}
+
+						WITNESS_PENDING_HEADERS
+							.set(&[<Inner::Chain as Chain>::NAME], self.pending_headers.len());
+						WITNESS_READY_HEADERS
+							.set(&[<Inner::Chain as Chain>::NAME], self.ready_headers.len());
 					}
 				}
 
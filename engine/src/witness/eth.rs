@@ -5,13 +5,18 @@ use std::{collections::HashMap, sync::Arc};
 
 use cf_chains::Ethereum;
 use cf_primitives::{chains::assets::eth, EpochIndex};
+use futures::StreamExt;
 use futures_core::Future;
 use sp_core::H160;
 use utilities::task_scope::Scope;
 
 use crate::{
 	db::PersistentKeyDB,
-	evm::{retry_rpc::EvmRetryRpcClient, rpc::EvmRpcSigningClient},
+	evm::{
+		retry_rpc::{EvmRetryRpcClient, EvmRetrySubscribeApi},
+		rpc::EvmRpcSigningClient,
+	},
+	health::LivenessReporter,
 	state_chain_observer::client::{
 		chain_api::ChainApi,
 		extrinsic_api::signed::SignedExtrinsicApi,
@@ -22,7 +27,10 @@ use crate::{
 	witness::evm::erc20_deposits::{flip::FlipEvents, usdc::UsdcEvents, usdt::UsdtEvents},
 };
 
-use super::{common::epoch_source::EpochSourceBuilder, evm::source::EvmSource};
+use super::{
+	common::epoch_source::EpochSourceBuilder,
+	evm::source::{EvmSource, BLOCK_PULL_TIMEOUT, RESTART_STREAM_DELAY},
+};
 use crate::witness::common::chain_source::extension::ChainSourceExt;
 
 use anyhow::{Context, Result};
@@ -37,6 +45,7 @@ pub async fn start<StateChainClient, StateChainStream, ProcessCall, ProcessingFu
 	state_chain_stream: StateChainStream,
 	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient, (), ()>,
 	db: Arc<PersistentKeyDB>,
+	liveness: LivenessReporter,
 ) -> Result<()>
 where
 	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi + 'static + Send + Sync,
@@ -98,6 +107,23 @@ where
 		.map(|(asset, address)| (address, asset.into()))
 		.collect();
 
+	// The chain source below is only polled while this node participates in an epoch, so the
+	// rpc's liveness is tracked through a block subscription of its own.
+	scope.spawn({
+		let eth_client = eth_client.clone();
+		async move {
+			loop {
+				let mut headers = eth_client.subscribe_blocks().await;
+				while let Ok(Some(Ok(_))) =
+					tokio::time::timeout(BLOCK_PULL_TIMEOUT, headers.next()).await
+				{
+					liveness.report_progress();
+				}
+				tokio::time::sleep(RESTART_STREAM_DELAY).await;
+			}
+		}
+	});
+
 	let eth_source = EvmSource::new(eth_client.clone()).strictly_monotonic().shared(scope);
 
 	eth_source
@@ -1,12 +1,35 @@
 use crate::{evm::retry_rpc::EvmRetryRpcApi, witness::common::chain_source::Header};
+use anyhow::{anyhow, ensure};
 use cf_chains::eth::EthereumTrackedData;
-use ethers::types::Bloom;
+use ethers::types::{Bloom, FeeHistory};
 use sp_core::U256;
 use utilities::context;
 
 use super::super::common::chunked_chain_source::chunked_by_time::chain_tracking::GetTrackedData;
 use ethers::types::H256;
 
+/// Priority fee percentiles sampled from the fee history of each block.
+const REWARD_PERCENTILES: [f64; 2] = [50.0, 75.0];
+
+/// Blocks that use more than this ratio of their gas limit are considered congested.
+const CONGESTION_GAS_USED_RATIO: f64 = 0.9;
+
+/// How the priority fee is chosen from the rewards sampled at each of the requested percentiles:
+/// the reward at the `congested` percentile index is used if the block used more than
+/// `congestion_threshold` of its gas limit, and the one at the `normal` percentile index otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PriorityFeeSelection {
+	normal: usize,
+	congested: usize,
+	congestion_threshold: f64,
+}
+
+const PRIORITY_FEE_SELECTION: PriorityFeeSelection = PriorityFeeSelection {
+	normal: 0,
+	congested: 1,
+	congestion_threshold: CONGESTION_GAS_USED_RATIO,
+};
+
 #[async_trait::async_trait]
 impl<T: EvmRetryRpcApi + Send + Sync + Clone> GetTrackedData<cf_chains::Ethereum, H256, Bloom>
 	for T
@@ -15,18 +38,138 @@ impl<T: EvmRetryRpcApi + Send + Sync + Clone> GetTrackedData<cf_chains::Ethereum
 		&self,
 		header: &Header<<cf_chains::Ethereum as cf_chains::Chain>::ChainBlockNumber, H256, Bloom>,
 	) -> Result<<cf_chains::Ethereum as cf_chains::Chain>::TrackedData, anyhow::Error> {
-		const PRIORITY_FEE_PERCENTILE: f64 = 50.0;
 		let fee_history = self
-			.fee_history(U256::one(), header.index.into(), vec![PRIORITY_FEE_PERCENTILE])
+			.fee_history(U256::one(), header.index.into(), REWARD_PERCENTILES.to_vec())
 			.await;
 
-		Ok(EthereumTrackedData {
-			base_fee: (*context!(fee_history.base_fee_per_gas.first())?)
-				.try_into()
-				.expect("Base fee should fit u128"),
-			priority_fee: (*context!(context!(fee_history.reward.first())?.first())?)
-				.try_into()
-				.expect("Priority fee should fit u128"),
-		})
+		tracked_data_from_fee_history(&fee_history, &REWARD_PERCENTILES, PRIORITY_FEE_SELECTION)
+	}
+}
+
+/// Builds the tracked data from a single block fee history, requested with the given
+/// `reward_percentiles`.
+fn tracked_data_from_fee_history(
+	fee_history: &FeeHistory,
+	reward_percentiles: &[f64],
+	selection: PriorityFeeSelection,
+) -> Result<EthereumTrackedData, anyhow::Error> {
+	let rewards = context!(fee_history.reward.first())?;
+	ensure!(
+		rewards.len() == reward_percentiles.len(),
+		"Expected rewards for {} percentiles, but the node returned {}",
+		reward_percentiles.len(),
+		rewards.len()
+	);
+
+	let percentile_index =
+		if *context!(fee_history.gas_used_ratio.first())? > selection.congestion_threshold {
+			selection.congested
+		} else {
+			selection.normal
+		};
+
+	Ok(EthereumTrackedData {
+		base_fee: (*context!(fee_history.base_fee_per_gas.first())?)
+			.try_into()
+			.map_err(|_| anyhow!("Base fee doesn't fit u128"))?,
+		priority_fee: (*context!(rewards.get(percentile_index))?)
+			.try_into()
+			.map_err(|_| anyhow!("Priority fee doesn't fit u128"))?,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fee_history(gas_used_ratio: f64, rewards: Vec<u64>) -> FeeHistory {
+		FeeHistory {
+			base_fee_per_gas: vec![U256::from(100)],
+			gas_used_ratio: vec![gas_used_ratio],
+			oldest_block: U256::from(1),
+			reward: vec![rewards.into_iter().map(U256::from).collect()],
+		}
+	}
+
+	#[test]
+	fn selects_priority_fee_percentile_based_on_congestion() {
+		let normal_block = fee_history(0.5, vec![10, 20]);
+		let congested_block = fee_history(0.95, vec![10, 20]);
+
+		assert_eq!(
+			tracked_data_from_fee_history(
+				&normal_block,
+				&REWARD_PERCENTILES,
+				PRIORITY_FEE_SELECTION
+			)
+			.unwrap(),
+			EthereumTrackedData { base_fee: 100, priority_fee: 10 }
+		);
+		assert_eq!(
+			tracked_data_from_fee_history(
+				&congested_block,
+				&REWARD_PERCENTILES,
+				PRIORITY_FEE_SELECTION
+			)
+			.unwrap(),
+			EthereumTrackedData { base_fee: 100, priority_fee: 20 }
+		);
+		assert_eq!(
+			tracked_data_from_fee_history(
+				&congested_block,
+				&REWARD_PERCENTILES,
+				PriorityFeeSelection { congested: 0, ..PRIORITY_FEE_SELECTION }
+			)
+			.unwrap(),
+			EthereumTrackedData { base_fee: 100, priority_fee: 10 }
+		);
+	}
+
+	#[test]
+	fn fails_if_fee_history_is_empty() {
+		for empty_fee_history in [
+			FeeHistory { reward: vec![], ..fee_history(0.5, vec![10, 20]) },
+			FeeHistory { base_fee_per_gas: vec![], ..fee_history(0.5, vec![10, 20]) },
+			FeeHistory { gas_used_ratio: vec![], ..fee_history(0.5, vec![10, 20]) },
+		] {
+			assert!(tracked_data_from_fee_history(
+				&empty_fee_history,
+				&REWARD_PERCENTILES,
+				PRIORITY_FEE_SELECTION
+			)
+			.is_err());
+		}
+	}
+
+	#[test]
+	fn fails_if_fees_do_not_fit_u128() {
+		let oversized_fee = U256::from(u128::MAX) + 1;
+		for oversized_fee_history in [
+			FeeHistory { base_fee_per_gas: vec![oversized_fee], ..fee_history(0.5, vec![10, 20]) },
+			FeeHistory { reward: vec![vec![oversized_fee; 2]], ..fee_history(0.5, vec![10, 20]) },
+		] {
+			assert!(tracked_data_from_fee_history(
+				&oversized_fee_history,
+				&REWARD_PERCENTILES,
+				PRIORITY_FEE_SELECTION
+			)
+			.is_err());
+		}
+	}
+
+	#[test]
+	fn fails_if_node_returns_wrong_number_of_percentiles() {
+		assert!(tracked_data_from_fee_history(
+			&fee_history(0.5, vec![10]),
+			&REWARD_PERCENTILES,
+			PRIORITY_FEE_SELECTION
+		)
+		.is_err());
+		assert!(tracked_data_from_fee_history(
+			&fee_history(0.5, vec![10, 20, 30]),
+			&REWARD_PERCENTILES,
+			PRIORITY_FEE_SELECTION
+		)
+		.is_err());
 	}
 }
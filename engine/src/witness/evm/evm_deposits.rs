@@ -404,6 +404,7 @@ mod tests {
 					"eth_subscribe",
 					"Ethereum",
 					Ethereum::WITNESS_PERIOD,
+					settings.eth.request_timeouts,
 				)
 				.unwrap();
 
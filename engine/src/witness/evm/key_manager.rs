@@ -212,6 +212,7 @@ mod tests {
 					"eth_subscribe",
 					"Ethereum",
 					Ethereum::WITNESS_PERIOD,
+					Default::default(),
 				)
 				.unwrap();
 
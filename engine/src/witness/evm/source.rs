@@ -40,10 +40,10 @@ where
 }
 
 /// The maximum amount of time we wait for a block to be pulled from the stream.
-const BLOCK_PULL_TIMEOUT: Duration = Duration::from_secs(60);
+pub(crate) const BLOCK_PULL_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// The time we wait before restarting the stream if we didn't get a block.
-const RESTART_STREAM_DELAY: Duration = Duration::from_secs(6);
+pub(crate) const RESTART_STREAM_DELAY: Duration = Duration::from_secs(6);
 
 #[async_trait::async_trait]
 impl<C, EvmChain> ChainSource for EvmSource<C, EvmChain>
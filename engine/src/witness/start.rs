@@ -7,6 +7,7 @@ use crate::{
 	db::PersistentKeyDB,
 	dot::retry_rpc::DotRetryRpcClient,
 	evm::{retry_rpc::EvmRetryRpcClient, rpc::EvmRpcSigningClient},
+	health::LivenessReporter,
 	state_chain_observer::client::{
 		extrinsic_api::signed::SignedExtrinsicApi,
 		storage_api::StorageApi,
@@ -35,6 +36,7 @@ pub async fn start<StateChainClient>(
 	state_chain_stream: impl StreamApi<FINALIZED> + Clone,
 	unfinalised_state_chain_stream: impl StreamApi<UNFINALIZED> + Clone,
 	db: Arc<PersistentKeyDB>,
+	eth_liveness: LivenessReporter,
 ) -> Result<()>
 where
 	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi + 'static + Send + Sync,
@@ -88,6 +90,7 @@ where
 		state_chain_stream.clone(),
 		epoch_source.clone(),
 		db.clone(),
+		eth_liveness,
 	);
 
 	let start_btc = super::btc::start(
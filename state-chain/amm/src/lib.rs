@@ -196,6 +196,29 @@ impl<LiquidityProvider: Clone + Ord> PoolState<LiquidityProvider> {
 		}
 	}
 
+	/// Returns the amount of the bought asset that swaps in the given direction could still
+	/// output, across limit and range orders, stopping as soon as `limit` is reached. Unlike
+	/// `limit_order_depth` and `range_order_depth`, this only visits the price levels needed to
+	/// cover `limit`.
+	///
+	/// This function never panics.
+	pub fn available_output(&self, order: Side, limit: Amount) -> Amount {
+		match order.to_sold_pair() {
+			Pairs::Base => self.inner_available_output::<BaseToQuote>(limit),
+			Pairs::Quote => self.inner_available_output::<QuoteToBase>(limit),
+		}
+	}
+
+	fn inner_available_output<SD: limit_orders::SwapDirection + range_orders::SwapDirection>(
+		&self,
+		limit: Amount,
+	) -> Amount {
+		let limit_orders = self.limit_orders.available_output::<SD>(limit);
+		limit_orders.saturating_add(
+			self.range_orders.available_output::<SD>(limit.saturating_sub(limit_orders)),
+		)
+	}
+
 	fn inner_swap<
 		SD: common::SwapDirection + limit_orders::SwapDirection + range_orders::SwapDirection,
 	>(
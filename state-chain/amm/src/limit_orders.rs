@@ -916,6 +916,21 @@ impl<LiquidityProvider: Clone + Ord> PoolState<LiquidityProvider> {
 			.collect()
 	}
 
+	/// Returns the assets available for swaps in a given direction, stopping as soon as `limit` is
+	/// reached, so only as many fixed pools as are needed to cover `limit` are visited.
+	///
+	/// This function never panics.
+	pub(super) fn available_output<SD: SwapDirection>(&self, limit: Amount) -> Amount {
+		let mut available = Amount::zero();
+		for fixed_pool in self.fixed_pools[!SD::INPUT_SIDE].values() {
+			if available >= limit {
+				break
+			}
+			available = available.saturating_add(fixed_pool.available);
+		}
+		available
+	}
+
 	/// Returns all the assets available for swaps between two prices (inclusive..exclusive)
 	///
 	/// This function never panics.
@@ -902,6 +902,45 @@ impl<LiquidityProvider: Clone + Ord> PoolState<LiquidityProvider> {
 		(total_output_amount, amount)
 	}
 
+	/// Returns the amount that swaps in the direction `SD` could output, stopping as soon as
+	/// `limit` is reached, so only the ticks needed to cover `limit` are traversed. The pool is
+	/// not modified.
+	///
+	/// This function never panics
+	pub(super) fn available_output<SD: SwapDirection>(&self, limit: Amount) -> Amount {
+		let mut available = Amount::zero();
+		let mut current_sqrt_price = self.current_sqrt_price;
+		let mut current_tick = self.current_tick;
+		let mut current_liquidity = self.current_liquidity;
+
+		while available < limit && SD::further_liquidity(current_tick) {
+			let Some((tick_at_delta, delta)) = (match SD::INPUT_SIDE {
+				Pairs::Base => self.liquidity_map.range(..=current_tick).next_back(),
+				Pairs::Quote => self.liquidity_map.range(current_tick + 1..).next(),
+			}) else {
+				break
+			};
+
+			let sqrt_price_at_delta = sqrt_price_at_tick(*tick_at_delta);
+			available = available.saturating_add(SD::output_amount_delta_floor(
+				current_sqrt_price,
+				sqrt_price_at_delta,
+				current_liquidity,
+			));
+
+			let Some(liquidity) =
+				current_liquidity.checked_add_signed(SD::liquidity_delta_on_crossing_tick(delta))
+			else {
+				break
+			};
+			current_sqrt_price = sqrt_price_at_delta;
+			current_tick = SD::current_tick_after_crossing_tick(*tick_at_delta);
+			current_liquidity = liquidity;
+		}
+
+		available
+	}
+
 	fn validate_position_range<T>(
 		lower_tick: Tick,
 		upper_tick: Tick,
@@ -208,6 +208,52 @@ fn test_basic_swaps() {
 	inner(Side::Buy);
 }
 
+#[test]
+fn test_available_output() {
+	fn inner(order: Side) {
+		let initial_sqrt_price = match order.to_sold_pair() {
+			Pairs::Base => MAX_SQRT_PRICE,
+			Pairs::Quote => MIN_SQRT_PRICE,
+		};
+		let mut pool_state = PoolState {
+			limit_orders: limit_orders::PoolState::new(0).unwrap(),
+			range_orders: range_orders::PoolState::new(0, initial_sqrt_price).unwrap(),
+		};
+
+		let (range_order_minted_amounts, _, _, _) = assert_ok!(pool_state
+			.collect_and_mint_range_order(
+				&LiquidityProvider::from([0; 32]),
+				-100..-10,
+				range_orders::Size::Liquidity { liquidity: 100000 },
+				Result::<_, Infallible>::Ok
+			));
+		let limit_order_liquidity: Amount = 10000.into();
+		assert_ok!(pool_state.collect_and_mint_limit_order(
+			&LiquidityProvider::from([0; 32]),
+			!order,
+			0,
+			limit_order_liquidity
+		));
+
+		// Only as much liquidity as is needed to cover the limit is counted.
+		assert_eq!(pool_state.available_output(order, 0.into()), 0.into());
+		assert_eq!(
+			pool_state.available_output(order, limit_order_liquidity),
+			limit_order_liquidity
+		);
+
+		let available = pool_state.available_output(order, Amount::MAX);
+		assert!(
+			available <= limit_order_liquidity + range_order_minted_amounts[!order.to_sold_pair()]
+		);
+		assert_eq!(pool_state.swap(order, Amount::MAX, None).0, available);
+		assert_eq!(pool_state.available_output(order, Amount::MAX), 0.into());
+	}
+
+	inner(Side::Sell);
+	inner(Side::Buy);
+}
+
 #[test]
 fn test_sqrt_price_to_price() {
 	assert_eq!(
@@ -496,3 +496,105 @@ fn can_move_through_multiple_epochs() {
 			assert_eq!(GENESIS_EPOCH + 20, Validator::epoch_index());
 		});
 }
+
+#[test]
+fn current_authorities_are_sorted_by_balance_and_only_change_once_rotation_completes() {
+	use state_chain_runtime::runtime_apis::runtime_decl_for_custom_runtime_api::CustomRuntimeApiV1;
+
+	const EPOCH_BLOCKS: u32 = 1000;
+	const MAX_AUTHORITIES: AuthorityCount = 10;
+	super::genesis::with_test_defaults()
+		.blocks_per_epoch(EPOCH_BLOCKS)
+		.max_authorities(MAX_AUTHORITIES)
+		.build()
+		.execute_with(|| {
+			let (mut testnet, genesis_authorities, backup_nodes) =
+				fund_authorities_and_join_auction(MAX_AUTHORITIES);
+
+			// Give the incoming authorities distinct balances.
+			for (i, node) in backup_nodes.iter().enumerate() {
+				testnet.state_chain_gateway_contract.fund_account(
+					node.clone(),
+					pallet_cf_funding::MinimumFunding::<Runtime>::get() * (i as u128 + 1),
+					GENESIS_EPOCH,
+				);
+			}
+			testnet.move_forward_blocks(2);
+
+			let assert_current_authorities = |expected: &[AccountId32]| {
+				let authorities = Runtime::cf_current_authorities();
+				assert_eq!(
+					authorities.iter().map(|(id, _)| id.clone()).collect::<BTreeSet<_>>(),
+					expected.iter().cloned().collect::<BTreeSet<_>>(),
+				);
+				for (id, balance) in &authorities {
+					assert_eq!(*balance, Flip::total_balance_of(id));
+				}
+				assert!(
+					authorities.windows(2).all(|pair| pair[0].1 >= pair[1].1),
+					"Authorities should be sorted by descending balance: {authorities:?}"
+				);
+			};
+
+			assert_current_authorities(&genesis_authorities);
+
+			// The outgoing authorities are returned while the rotation is in progress.
+			testnet.move_to_the_end_of_epoch();
+			testnet.move_forward_blocks(4);
+			assert!(matches!(
+				Validator::current_rotation_phase(),
+				RotationPhase::KeygensInProgress(..)
+			));
+			assert_current_authorities(&genesis_authorities);
+
+			testnet.move_forward_blocks(VAULT_ROTATION_BLOCKS - 4);
+			assert!(matches!(Validator::current_rotation_phase(), RotationPhase::Idle));
+			assert_eq!(GENESIS_EPOCH + 1, Validator::epoch_index());
+			assert_current_authorities(&backup_nodes);
+			assert_eq!(
+				Runtime::cf_current_authorities().first().map(|(id, _)| id),
+				backup_nodes.last(),
+				"The last backup node was funded the most"
+			);
+		});
+}
+
+#[test]
+fn epoch_info_matches_the_individual_queries() {
+	use state_chain_runtime::runtime_apis::runtime_decl_for_custom_runtime_api::CustomRuntimeApiV1;
+
+	const EPOCH_BLOCKS: u32 = 1000;
+	const MAX_AUTHORITIES: AuthorityCount = 10;
+	super::genesis::with_test_defaults()
+		.blocks_per_epoch(EPOCH_BLOCKS)
+		.max_authorities(MAX_AUTHORITIES)
+		.build()
+		.execute_with(|| {
+			let (mut testnet, _, _) = fund_authorities_and_join_auction(MAX_AUTHORITIES);
+
+			let assert_epoch_info_matches = || {
+				let epoch_info = Runtime::cf_epoch_info();
+				assert_eq!(epoch_info.epoch_index, Runtime::cf_current_epoch());
+				assert_eq!(epoch_info.bond, Validator::bond());
+				assert_eq!(
+					epoch_info.authority_count as usize,
+					Runtime::cf_authorities_at_epoch(Runtime::cf_current_epoch()).len()
+				);
+				assert_eq!(epoch_info.is_auction_phase, Runtime::cf_is_auction_phase());
+				epoch_info
+			};
+
+			let epoch_info = assert_epoch_info_matches();
+			assert_eq!(epoch_info.epoch_index, GENESIS_EPOCH);
+			assert!(!epoch_info.is_auction_phase);
+
+			testnet.move_to_the_end_of_epoch();
+			testnet.move_forward_blocks(4);
+			assert!(assert_epoch_info_matches().is_auction_phase);
+
+			testnet.move_forward_blocks(VAULT_ROTATION_BLOCKS - 4);
+			let epoch_info = assert_epoch_info_matches();
+			assert_eq!(epoch_info.epoch_index, GENESIS_EPOCH + 1);
+			assert!(!epoch_info.is_auction_phase);
+		});
+}
@@ -121,6 +121,52 @@ fn cannot_redeem_funds_out_of_redemption_period() {
 		});
 }
 
+#[test]
+fn pending_redemptions_are_returned_by_the_runtime_api() {
+	use state_chain_runtime::runtime_apis::runtime_decl_for_custom_runtime_api::CustomRuntimeApiV1;
+
+	const EPOCH_BLOCKS: u32 = 100;
+	const MAX_AUTHORITIES: AuthorityCount = 3;
+	const REDEMPTION_AMOUNT: FlipBalance = MIN_FUNDING + 1;
+	super::genesis::with_test_defaults()
+		.blocks_per_epoch(EPOCH_BLOCKS)
+		.max_authorities(MAX_AUTHORITIES)
+		.build()
+		.execute_with(|| {
+			let nodes = Validator::current_authorities();
+			let (mut testnet, _) = network::Network::create(0, &nodes);
+			for node in &nodes {
+				testnet.state_chain_gateway_contract.fund_account(
+					node.clone(),
+					GENESIS_BALANCE,
+					GENESIS_EPOCH,
+				);
+			}
+			testnet.move_forward_blocks(1);
+
+			let redeeming_node = nodes[0].clone();
+			assert_eq!(Runtime::cf_pending_redemption(redeeming_node.clone()), None);
+			assert_eq!(Runtime::cf_all_pending_redemptions(), vec![]);
+
+			assert_ok!(Funding::redeem(
+				RuntimeOrigin::signed(redeeming_node.clone()),
+				REDEMPTION_AMOUNT.into(),
+				ETH_DUMMY_ADDR,
+				Default::default()
+			));
+
+			assert_eq!(
+				Runtime::cf_pending_redemption(redeeming_node.clone()),
+				Some(REDEMPTION_AMOUNT)
+			);
+			assert_eq!(Runtime::cf_pending_redemption(nodes[1].clone()), None);
+			assert_eq!(
+				Runtime::cf_all_pending_redemptions(),
+				vec![(redeeming_node, REDEMPTION_AMOUNT)]
+			);
+		});
+}
+
 #[test]
 fn funded_node_is_added_to_backups() {
 	const EPOCH_BLOCKS: u32 = 10_000_000;
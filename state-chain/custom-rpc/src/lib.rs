@@ -24,7 +24,9 @@ use jsonrpsee::{
 	SubscriptionSink,
 };
 use pallet_cf_governance::GovCallHash;
-use pallet_cf_pools::{AskBidMap, PoolInfo, PoolLiquidity, PoolPriceV1, UnidirectionalPoolDepth};
+use pallet_cf_pools::{
+	AskBidMap, PoolInfo, PoolLiquidity, PoolPriceV1, PoolSummary, UnidirectionalPoolDepth,
+};
 use pallet_cf_swapping::SwapLegInfo;
 use sc_client_api::{BlockchainEvents, HeaderBackend};
 use serde::{Deserialize, Serialize};
@@ -306,6 +308,13 @@ pub struct RpcPenalty {
 	suspension_duration_blocks: u32,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct RpcKeygenParticipationStats {
+	successful_keygens: u32,
+	failed_keygens: u32,
+	timeouts: u32,
+}
+
 type RpcSuspensions = Vec<(Offence, Vec<(u32, state_chain_runtime::AccountId)>)>;
 
 #[derive(Serialize, Deserialize)]
@@ -318,6 +327,14 @@ pub struct RpcAuctionState {
 	min_active_bid: Option<NumberOrHex>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct RpcEpochInfo {
+	epoch_index: EpochIndex,
+	bond: NumberOrHex,
+	authority_count: u32,
+	is_auction_phase: bool,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct RpcSwapOutputV1 {
 	// Intermediary amount, if there's any
@@ -541,6 +558,48 @@ mod boost_pool_rpc {
 	}
 }
 
+/// A runtime event emitted in a finalized block.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RpcEvent {
+	pub block_number: BlockNumber,
+	pub pallet: String,
+	pub variant: String,
+	pub fields: scale_value::Composite<()>,
+}
+
+impl RpcEvent {
+	/// A decoded `RuntimeEvent` is a variant named after the pallet, wrapping the pallet's event.
+	fn from_decoded_event(block_number: BlockNumber, event: scale_value::Value) -> Option<Self> {
+		let scale_value::ValueDef::Variant(scale_value::Variant { name: pallet, values }) =
+			event.value
+		else {
+			return None
+		};
+		let scale_value::ValueDef::Variant(scale_value::Variant { name: variant, values: fields }) =
+			values.into_values().next()?.value
+		else {
+			return None
+		};
+		Some(Self { block_number, pallet, variant, fields })
+	}
+}
+
+/// Decodes the events of a block, keeping only those of `pallet` if it is provided.
+fn decode_events(
+	event_decoder: &type_decoder::TypeDecoder,
+	block_number: BlockNumber,
+	events: impl IntoIterator<Item = state_chain_runtime::RuntimeEvent>,
+	pallet: Option<&str>,
+) -> Vec<RpcEvent> {
+	events
+		.into_iter()
+		.filter_map(|event| {
+			RpcEvent::from_decoded_event(block_number, event_decoder.decode_data(event.encode()))
+		})
+		.filter(|event| pallet.map_or(true, |pallet| event.pallet == pallet))
+		.collect()
+}
+
 type BoostPoolDepthResponse = Vec<BoostPoolDepth>;
 type BoostPoolDetailsResponse = Vec<boost_pool_rpc::BoostPoolDetailsRpc>;
 type BoostPoolFeesResponse = Vec<boost_pool_rpc::BoostPoolFeesRpc>;
@@ -583,6 +642,18 @@ pub trait CustomApi {
 	fn cf_epoch_duration(&self, at: Option<state_chain_runtime::Hash>) -> RpcResult<u32>;
 	#[method(name = "current_epoch_started_at")]
 	fn cf_current_epoch_started_at(&self, at: Option<state_chain_runtime::Hash>) -> RpcResult<u32>;
+	#[method(name = "authorities_at_epoch")]
+	fn cf_authorities_at_epoch(
+		&self,
+		epoch_index: EpochIndex,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<state_chain_runtime::AccountId>>;
+	/// Returns the current authorities with their total FLIP balance, highest balance first.
+	#[method(name = "current_authorities", aliases = ["cf_validators"])]
+	fn cf_current_authorities(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<(state_chain_runtime::AccountId, NumberOrHex)>>;
 	#[method(name = "authority_emission_per_block")]
 	fn cf_authority_emission_per_block(
 		&self,
@@ -598,6 +669,19 @@ pub trait CustomApi {
 		&self,
 		at: Option<state_chain_runtime::Hash>,
 	) -> RpcResult<(NumberOrHex, NumberOrHex)>;
+	/// Returns the amount of the account's pending redemption, or `None` if it has none.
+	#[method(name = "pending_redemption")]
+	fn cf_pending_redemption(
+		&self,
+		account_id: state_chain_runtime::AccountId,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+	/// Returns the amount of every pending redemption, by account.
+	#[method(name = "all_pending_redemptions")]
+	fn cf_all_pending_redemptions(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<(state_chain_runtime::AccountId, NumberOrHex)>>;
 	#[method(name = "accounts")]
 	fn cf_accounts(
 		&self,
@@ -628,6 +712,12 @@ pub trait CustomApi {
 	) -> RpcResult<Vec<(Offence, RpcPenalty)>>;
 	#[method(name = "suspensions")]
 	fn cf_suspensions(&self, at: Option<state_chain_runtime::Hash>) -> RpcResult<RpcSuspensions>;
+	#[method(name = "offences")]
+	fn cf_offences(
+		&self,
+		account_id: state_chain_runtime::AccountId,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<(Offence, u32)>>;
 	#[method(name = "generate_gov_key_call_hash")]
 	fn cf_generate_gov_key_call_hash(
 		&self,
@@ -637,6 +727,8 @@ pub trait CustomApi {
 	#[method(name = "auction_state")]
 	fn cf_auction_state(&self, at: Option<state_chain_runtime::Hash>)
 		-> RpcResult<RpcAuctionState>;
+	#[method(name = "epoch_info")]
+	fn cf_epoch_info(&self, at: Option<state_chain_runtime::Hash>) -> RpcResult<RpcEpochInfo>;
 	#[method(name = "pool_price")]
 	fn cf_pool_price(
 		&self,
@@ -706,6 +798,8 @@ pub trait CustomApi {
 		quote_asset: Asset,
 		at: Option<state_chain_runtime::Hash>,
 	) -> RpcResult<PoolLiquidity>;
+	#[method(name = "all_pools")]
+	fn cf_all_pools(&self, at: Option<state_chain_runtime::Hash>) -> RpcResult<Vec<PoolSummary>>;
 	#[method(name = "pool_orders")]
 	fn cf_pool_orders(
 		&self,
@@ -747,10 +841,17 @@ pub trait CustomApi {
 	fn cf_environment(&self, at: Option<state_chain_runtime::Hash>) -> RpcResult<RpcEnvironment>;
 	#[deprecated(note = "Use direct storage access of `CurrentReleaseVersion` instead.")]
 	#[method(name = "current_compatibility_version")]
-	fn cf_current_compatibility_version(&self) -> RpcResult<SemVer>;
+	fn cf_current_compatibility_version(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<SemVer>;
 
 	#[method(name = "max_swap_amount")]
-	fn cf_max_swap_amount(&self, asset: Asset) -> RpcResult<Option<AssetAmount>>;
+	fn cf_max_swap_amount(
+		&self,
+		asset: Asset,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Option<AssetAmount>>;
 	#[subscription(name = "subscribe_pool_price", item = PoolPriceV1)]
 	fn cf_subscribe_pool_price(&self, from_asset: Asset, to_asset: Asset);
 	#[subscription(name = "subscribe_pool_price_v2", item = BlockUpdate<PoolPriceV2>)]
@@ -764,6 +865,16 @@ pub trait CustomApi {
 	#[subscription(name = "subscribe_scheduled_swaps", item = BlockUpdate<SwapResponse>)]
 	fn cf_subscribe_scheduled_swaps(&self, base_asset: Asset, quote_asset: Asset);
 
+	// Subscribe to a stream that produces an update whenever the auction phase starts or ends in a
+	// finalized block.
+	#[subscription(name = "subscribe_auction_phase", item = BlockUpdate<bool>)]
+	fn cf_subscribe_auction_phase(&self);
+
+	// Subscribe to a stream of the events emitted in finalized blocks, optionally only those of a
+	// single pallet.
+	#[subscription(name = "subscribe_events", item = RpcEvent)]
+	fn cf_subscribe_events(&self, pallet: Option<String>);
+
 	#[method(name = "scheduled_swaps")]
 	fn cf_scheduled_swaps(
 		&self,
@@ -788,12 +899,14 @@ pub trait CustomApi {
 	fn cf_failed_call_ethereum(
 		&self,
 		broadcast_id: BroadcastId,
+		at: Option<state_chain_runtime::Hash>,
 	) -> RpcResult<Option<<cf_chains::Ethereum as Chain>::Transaction>>;
 
 	#[method(name = "failed_call_arbitrum")]
 	fn cf_failed_call_arbitrum(
 		&self,
 		broadcast_id: BroadcastId,
+		at: Option<state_chain_runtime::Hash>,
 	) -> RpcResult<Option<<cf_chains::Arbitrum as Chain>::Transaction>>;
 
 	#[method(name = "witness_count")]
@@ -835,6 +948,26 @@ pub trait CustomApi {
 		asset: Option<Asset>,
 		at: Option<state_chain_runtime::Hash>,
 	) -> RpcResult<BoostPoolFeesResponse>;
+
+	/// Returns the vault of the given chain for the given epoch in the form
+	/// [agg_key, active_from_block], where the agg key is SCALE-encoded.
+	#[method(name = "vault")]
+	fn cf_vault(
+		&self,
+		chain: ForeignChain,
+		epoch_index: EpochIndex,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<(String, u64)>;
+
+	/// Returns how often the given validator succeeded, failed or timed out in the keygen and key
+	/// handover ceremonies of the given chain.
+	#[method(name = "keygen_participation_stats")]
+	fn cf_keygen_participation_stats(
+		&self,
+		chain: ForeignChain,
+		account_id: state_chain_runtime::AccountId,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<RpcKeygenParticipationStats>;
 }
 
 /// An RPC extension for the state chain node.
@@ -964,6 +1097,29 @@ where
 			.cf_current_epoch_started_at(self.unwrap_or_best(at))
 			.map_err(to_rpc_error)
 	}
+	fn cf_authorities_at_epoch(
+		&self,
+		epoch_index: EpochIndex,
+		at: Option<<B as BlockT>::Hash>,
+	) -> RpcResult<Vec<state_chain_runtime::AccountId>> {
+		self.client
+			.runtime_api()
+			.cf_authorities_at_epoch(self.unwrap_or_best(at), epoch_index)
+			.map_err(to_rpc_error)
+	}
+	fn cf_current_authorities(
+		&self,
+		at: Option<<B as BlockT>::Hash>,
+	) -> RpcResult<Vec<(state_chain_runtime::AccountId, NumberOrHex)>> {
+		Ok(self
+			.client
+			.runtime_api()
+			.cf_current_authorities(self.unwrap_or_best(at))
+			.map_err(to_rpc_error)?
+			.into_iter()
+			.map(|(account_id, balance)| (account_id, balance.into()))
+			.collect())
+	}
 	fn cf_authority_emission_per_block(
 		&self,
 		at: Option<<B as BlockT>::Hash>,
@@ -994,6 +1150,30 @@ where
 			.map_err(to_rpc_error)
 			.map(|(issuance, offchain)| (issuance.into(), offchain.into()))
 	}
+	fn cf_pending_redemption(
+		&self,
+		account_id: state_chain_runtime::AccountId,
+		at: Option<<B as BlockT>::Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		self.client
+			.runtime_api()
+			.cf_pending_redemption(self.unwrap_or_best(at), account_id)
+			.map_err(to_rpc_error)
+			.map(|amount| amount.map(Into::into))
+	}
+	fn cf_all_pending_redemptions(
+		&self,
+		at: Option<<B as BlockT>::Hash>,
+	) -> RpcResult<Vec<(state_chain_runtime::AccountId, NumberOrHex)>> {
+		Ok(self
+			.client
+			.runtime_api()
+			.cf_all_pending_redemptions(self.unwrap_or_best(at))
+			.map_err(to_rpc_error)?
+			.into_iter()
+			.map(|(account_id, amount)| (account_id, amount.into()))
+			.collect())
+	}
 	fn cf_accounts(
 		&self,
 		at: Option<<B as BlockT>::Hash>,
@@ -1125,6 +1305,16 @@ where
 			.cf_suspensions(self.unwrap_or_best(at))
 			.map_err(to_rpc_error)
 	}
+	fn cf_offences(
+		&self,
+		account_id: state_chain_runtime::AccountId,
+		at: Option<<B as BlockT>::Hash>,
+	) -> RpcResult<Vec<(Offence, u32)>> {
+		self.client
+			.runtime_api()
+			.cf_offences(self.unwrap_or_best(at), account_id)
+			.map_err(to_rpc_error)
+	}
 
 	fn cf_generate_gov_key_call_hash(
 		&self,
@@ -1154,6 +1344,21 @@ where
 		})
 	}
 
+	fn cf_epoch_info(&self, at: Option<<B as BlockT>::Hash>) -> RpcResult<RpcEpochInfo> {
+		let epoch_info = self
+			.client
+			.runtime_api()
+			.cf_epoch_info(self.unwrap_or_best(at))
+			.map_err(to_rpc_error)?;
+
+		Ok(RpcEpochInfo {
+			epoch_index: epoch_info.epoch_index,
+			bond: epoch_info.bond.into(),
+			authority_count: epoch_info.authority_count,
+			is_auction_phase: epoch_info.is_auction_phase,
+		})
+	}
+
 	fn cf_pool_price(
 		&self,
 		from_asset: Asset,
@@ -1313,6 +1518,13 @@ where
 			.and_then(|result| result.map_err(map_dispatch_error))
 	}
 
+	fn cf_all_pools(&self, at: Option<<B as BlockT>::Hash>) -> RpcResult<Vec<PoolSummary>> {
+		self.client
+			.runtime_api()
+			.cf_all_pools(self.unwrap_or_best(at))
+			.map_err(to_rpc_error)
+	}
+
 	fn cf_required_asset_ratio_for_range_order(
 		&self,
 		base_asset: Asset,
@@ -1486,18 +1698,25 @@ where
 		})
 	}
 
-	fn cf_current_compatibility_version(&self) -> RpcResult<SemVer> {
+	fn cf_current_compatibility_version(
+		&self,
+		at: Option<<B as BlockT>::Hash>,
+	) -> RpcResult<SemVer> {
 		#[allow(deprecated)]
 		self.client
 			.runtime_api()
-			.cf_current_compatibility_version(self.unwrap_or_best(None))
+			.cf_current_compatibility_version(self.unwrap_or_best(at))
 			.map_err(to_rpc_error)
 	}
 
-	fn cf_max_swap_amount(&self, asset: Asset) -> RpcResult<Option<AssetAmount>> {
+	fn cf_max_swap_amount(
+		&self,
+		asset: Asset,
+		at: Option<<B as BlockT>::Hash>,
+	) -> RpcResult<Option<AssetAmount>> {
 		self.client
 			.runtime_api()
-			.cf_max_swap_amount(self.unwrap_or_best(None), asset)
+			.cf_max_swap_amount(self.unwrap_or_best(at), asset)
 			.map_err(to_rpc_error)
 	}
 
@@ -1565,6 +1784,129 @@ where
 		)
 	}
 
+	fn cf_subscribe_auction_phase(
+		&self,
+		mut sink: SubscriptionSink,
+	) -> Result<(), SubscriptionEmptyError> {
+		use futures::{future::FutureExt, stream::StreamExt};
+
+		let client = self.client.clone();
+		let auction_phase_at =
+			move |hash: state_chain_runtime::Hash| -> RpcResult<BlockUpdate<bool>> {
+				Ok(BlockUpdate {
+					block_hash: hash,
+					block_number: client
+						.number(hash)
+						.map_err(to_rpc_error)?
+						.ok_or_else(|| anyhow::anyhow!("Block {hash:?} not found"))?,
+					data: client.runtime_api().cf_is_auction_phase(hash).map_err(to_rpc_error)?,
+				})
+			};
+
+		let initial = match auction_phase_at(self.client.info().finalized_hash) {
+			Ok(initial) => initial,
+			Err(e) => {
+				let _ = sink.reject(e);
+				return Ok(())
+			},
+		};
+
+		let mut previous = initial.data;
+		let stream = futures::stream::iter(std::iter::once(Ok(initial))).chain(
+			self.client.finality_notification_stream().flat_map(move |notification| {
+				futures::stream::iter(
+					// Blocks can be finalized implicitly, along with one of their descendants.
+					notification
+						.tree_route
+						.iter()
+						.chain(std::iter::once(&notification.hash))
+						.filter_map(|hash| match auction_phase_at(*hash) {
+							Ok(update) if update.data != previous => {
+								previous = update.data;
+								Some(Ok::<_, jsonrpsee::core::Error>(update))
+							},
+							Ok(_) => None,
+							Err(error) => {
+								log::warn!(
+									"Failed to query the auction phase at {hash:?}: {error}"
+								);
+								None
+							},
+						})
+						.collect::<Vec<_>>(),
+				)
+			}),
+		);
+
+		self.executor.spawn(
+			"cf-rpc-auction-phase-subscription",
+			Some("rpc"),
+			async move {
+				if let SubscriptionClosed::Failed(err) = sink.pipe_from_try_stream(stream).await {
+					log::error!("Subscription closed due to error: {err:?}");
+					sink.close(err);
+				}
+			}
+			.boxed(),
+		);
+
+		Ok(())
+	}
+
+	fn cf_subscribe_events(
+		&self,
+		mut sink: SubscriptionSink,
+		pallet: Option<String>,
+	) -> Result<(), SubscriptionEmptyError> {
+		use futures::{future::FutureExt, stream::StreamExt};
+
+		let client = self.client.clone();
+		let event_decoder = type_decoder::TypeDecoder::new::<state_chain_runtime::RuntimeEvent>();
+		let events_in_block = move |hash: state_chain_runtime::Hash| -> RpcResult<Vec<RpcEvent>> {
+			let block_number = client
+				.number(hash)
+				.map_err(to_rpc_error)?
+				.ok_or_else(|| anyhow::anyhow!("Block {hash:?} not found"))?;
+			let events = client
+				.runtime_api()
+				.cf_get_events(hash, EventFilter::AllEvents)
+				.map_err(to_rpc_error)?
+				.into_iter()
+				.map(|event_record| event_record.event);
+			Ok(decode_events(&event_decoder, block_number, events, pallet.as_deref()))
+		};
+
+		let stream = self.client.finality_notification_stream().flat_map(move |notification| {
+			futures::stream::iter(
+				// Blocks can be finalized implicitly, along with one of their descendants.
+				match notification
+					.tree_route
+					.iter()
+					.chain(std::iter::once(&notification.hash))
+					.map(|hash| events_in_block(*hash))
+					.collect::<RpcResult<Vec<_>>>()
+				{
+					Ok(events) => events.into_iter().flatten().map(Ok).collect(),
+					Err(error) => vec![Err(error)],
+				},
+			)
+		});
+
+		self.executor.spawn(
+			"cf-rpc-events-subscription",
+			Some("rpc"),
+			async move {
+				if let SubscriptionClosed::Failed(err) = sink.pipe_from_try_stream(stream).await {
+					log::error!("Subscription closed due to error: {err:?}");
+					sink.close(err);
+				}
+			}
+			.boxed(),
+		);
+
+		Ok(())
+	}
+
 	fn cf_scheduled_swaps(
 		&self,
 		base_asset: Asset,
@@ -1644,20 +1986,22 @@ where
 	fn cf_failed_call_ethereum(
 		&self,
 		broadcast_id: BroadcastId,
+		at: Option<<B as BlockT>::Hash>,
 	) -> RpcResult<Option<<cf_chains::Ethereum as Chain>::Transaction>> {
 		self.client
 			.runtime_api()
-			.cf_failed_call_ethereum(self.unwrap_or_best(None), broadcast_id)
+			.cf_failed_call_ethereum(self.unwrap_or_best(at), broadcast_id)
 			.map_err(to_rpc_error)
 	}
 
 	fn cf_failed_call_arbitrum(
 		&self,
 		broadcast_id: BroadcastId,
+		at: Option<<B as BlockT>::Hash>,
 	) -> RpcResult<Option<<cf_chains::Arbitrum as Chain>::Transaction>> {
 		self.client
 			.runtime_api()
-			.cf_failed_call_arbitrum(self.unwrap_or_best(None), broadcast_id)
+			.cf_failed_call_arbitrum(self.unwrap_or_best(at), broadcast_id)
 			.map_err(to_rpc_error)
 	}
 
@@ -1747,6 +2091,38 @@ where
 				.map_err(to_rpc_error)
 		})
 	}
+
+	fn cf_vault(
+		&self,
+		chain: ForeignChain,
+		epoch_index: EpochIndex,
+		at: Option<<B as BlockT>::Hash>,
+	) -> RpcResult<(String, u64)> {
+		self.client
+			.runtime_api()
+			.cf_vault(self.unwrap_or_best(at), chain, epoch_index)
+			.map_err(to_rpc_error)?
+			.map(|(agg_key, active_from_block)| (hex::encode(agg_key), active_from_block))
+			.ok_or_else(|| anyhow::anyhow!("No {chain} vault found for epoch {epoch_index}").into())
+	}
+
+	fn cf_keygen_participation_stats(
+		&self,
+		chain: ForeignChain,
+		account_id: state_chain_runtime::AccountId,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<RpcKeygenParticipationStats> {
+		let stats = self
+			.client
+			.runtime_api()
+			.cf_keygen_participation_stats(self.unwrap_or_best(at), chain, account_id)
+			.map_err(to_rpc_error)?;
+		Ok(RpcKeygenParticipationStats {
+			successful_keygens: stats.successful_keygens,
+			failed_keygens: stats.failed_keygens,
+			timeouts: stats.timeouts,
+		})
+	}
 }
 
 impl<C, B> CustomRpc<C, B>
@@ -2170,4 +2546,36 @@ mod test {
 		})
 		.unwrap());
 	}
+
+	#[test]
+	fn test_events_are_filtered_by_pallet() {
+		let event_decoder = type_decoder::TypeDecoder::new::<state_chain_runtime::RuntimeEvent>();
+		let events = vec![
+			state_chain_runtime::RuntimeEvent::System(frame_system::Event::CodeUpdated),
+			state_chain_runtime::RuntimeEvent::Governance(pallet_cf_governance::Event::Proposed(1)),
+			state_chain_runtime::RuntimeEvent::Governance(pallet_cf_governance::Event::Executed(1)),
+		];
+		let pallets_and_variants = |events: Vec<RpcEvent>| {
+			events
+				.into_iter()
+				.map(|event| (event.pallet, event.variant))
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			pallets_and_variants(decode_events(&event_decoder, 1, events.clone(), None)),
+			vec![
+				("System".to_string(), "CodeUpdated".to_string()),
+				("Governance".to_string(), "Proposed".to_string()),
+				("Governance".to_string(), "Executed".to_string()),
+			]
+		);
+		assert_eq!(
+			pallets_and_variants(decode_events(&event_decoder, 1, events, Some("Governance"))),
+			vec![
+				("Governance".to_string(), "Proposed".to_string()),
+				("Governance".to_string(), "Executed".to_string()),
+			]
+		);
+	}
 }
@@ -618,6 +618,8 @@ fn testnet_genesis(
 		aura: state_chain_runtime::AuraConfig { authorities: vec![] },
 		// These are set indirectly via the session pallet.
 		grandpa: state_chain_runtime::GrandpaConfig { authorities: vec![], ..Default::default() },
+		// The root key alone is below the minimum governance membership, so the chain starts out
+		// bootstrapping until the root key has added the rest of the governance set.
 		governance: state_chain_runtime::GovernanceConfig {
 			members: BTreeSet::from([root_key]),
 			expiry_span,
@@ -10,6 +10,15 @@ use frame_support::{
 use frame_system::RawOrigin;
 use sp_std::collections::btree_set::BTreeSet;
 
+/// A governance set of the minimum allowed size that includes `caller`.
+fn minimum_members<T: Config>(caller: T::AccountId) -> BTreeSet<T::AccountId> {
+	let mut members = BTreeSet::from([caller]);
+	for i in 1..T::MinimumMembers::get() {
+		members.insert(account("member", i, 0));
+	}
+	members
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -36,7 +45,7 @@ mod benchmarks {
 		let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
 		let caller: T::AccountId = whitelisted_caller();
 		<Members<T>>::put(BTreeSet::from([caller.clone()]));
-		Pallet::<T>::push_proposal(Box::new(call), ExecutionMode::Automatic);
+		Pallet::<T>::push_proposal(caller.clone(), Box::new(call), ExecutionMode::Automatic);
 
 		#[extrinsic_call]
 		approve(RawOrigin::Signed(caller.clone()), 1);
@@ -47,7 +56,7 @@ mod benchmarks {
 	#[benchmark]
 	fn new_membership_set() {
 		let caller: T::AccountId = whitelisted_caller();
-		let members = BTreeSet::from([caller]);
+		let members = minimum_members::<T>(caller);
 		let call =
 			Call::<T>::new_membership_set { new_members: members.clone().into_iter().collect() };
 		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
@@ -78,7 +87,7 @@ mod benchmarks {
 	fn on_initialize(b: Linear<1, 100>) {
 		for _n in 1..b {
 			let call = Box::new(frame_system::Call::remark { remark: vec![] }.into());
-			Pallet::<T>::push_proposal(call, ExecutionMode::Automatic);
+			Pallet::<T>::push_proposal(whitelisted_caller(), call, ExecutionMode::Automatic);
 		}
 		#[block]
 		{
@@ -98,7 +107,7 @@ mod benchmarks {
 	fn expire_proposals(b: Linear<1, 100>) {
 		for _ in 1..b {
 			let call = Box::new(frame_system::Call::remark { remark: vec![] }.into());
-			Pallet::<T>::push_proposal(call, ExecutionMode::Automatic);
+			Pallet::<T>::push_proposal(whitelisted_caller(), call, ExecutionMode::Automatic);
 		}
 
 		#[block]
@@ -128,8 +137,10 @@ mod benchmarks {
 		let next_nonce = 788;
 		NextGovKeyCallHashNonce::<T>::put(next_nonce);
 
-		let new_membership_set_call: <T as Config>::RuntimeCall =
-			Call::<T>::new_membership_set { new_members: Default::default() }.into();
+		let new_membership_set_call: <T as Config>::RuntimeCall = Call::<T>::new_membership_set {
+			new_members: minimum_members::<T>(whitelisted_caller()),
+		}
+		.into();
 
 		let call_hash = frame_support::Hashable::blake2_256(&(
 			new_membership_set_call.clone(),
@@ -155,15 +166,55 @@ mod benchmarks {
 	#[benchmark]
 	fn dispatch_whitelisted_call() {
 		let caller: T::AccountId = whitelisted_caller();
-		<Members<T>>::put(BTreeSet::from([caller.clone()]));
+		let members = minimum_members::<T>(caller.clone());
+		<Members<T>>::put(members.clone());
 		let call: <T as Config>::RuntimeCall =
-			Call::<T>::new_membership_set { new_members: Default::default() }.into();
-		Pallet::<T>::push_proposal(Box::new(call.clone()), ExecutionMode::Manual);
+			Call::<T>::new_membership_set { new_members: members }.into();
+		Pallet::<T>::push_proposal(caller.clone(), Box::new(call.clone()), ExecutionMode::Manual);
 		PreAuthorisedGovCalls::<T>::insert(1, call.encode());
 
 		#[extrinsic_call]
 		dispatch_whitelisted_call(RawOrigin::Signed(caller.clone()), 1);
 	}
 
+	#[benchmark]
+	fn add_member() {
+		let new_member: T::AccountId = account("new_member", 0, 0);
+		let call = Call::<T>::add_member { account_id: new_member.clone() };
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		assert!(Members::<T>::get().contains(&new_member));
+	}
+
+	#[benchmark]
+	fn remove_member(b: Linear<1, 100>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let mut members = minimum_members::<T>(caller.clone());
+		members.insert(account("member", 0, 0));
+		<Members<T>>::put(members);
+		for _ in 0..b {
+			let call = Box::new(frame_system::Call::remark { remark: vec![] }.into());
+			let proposal_id =
+				Pallet::<T>::push_proposal(caller.clone(), call, ExecutionMode::Automatic);
+			Proposals::<T>::mutate_extant(proposal_id, |proposal| {
+				proposal.approved.insert(caller.clone());
+			});
+		}
+		let call = Call::<T>::remove_member { account_id: caller.clone() };
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		assert!(!Members::<T>::get().contains(&caller));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -25,7 +25,7 @@ pub use weights::WeightInfo;
 /// Hash over (call, nonce, runtime_version)
 pub type GovCallHash = [u8; 32];
 
-pub const PALLET_VERSION: StorageVersion = StorageVersion::new(2);
+pub const PALLET_VERSION: StorageVersion = StorageVersion::new(4);
 
 #[cfg(test)]
 mod mock;
@@ -68,10 +68,13 @@ pub mod pallet {
 		Manual,
 	}
 
-	#[derive(Encode, Decode, TypeInfo, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
-	pub struct ActiveProposal {
+	#[derive(Encode, Decode, TypeInfo, Clone, RuntimeDebug, PartialEq, Eq)]
+	pub struct ActiveProposal<AccountId> {
 		pub proposal_id: ProposalId,
 		pub expiry_time: Timestamp,
+		/// The member who submitted the proposal. Unknown for proposals submitted before the
+		/// proposer was recorded.
+		pub proposer: Option<AccountId>,
 	}
 
 	/// Proposal struct
@@ -116,6 +119,18 @@ pub mod pallet {
 		type CompatibleCfeVersions: CompatibleCfeVersions;
 		/// For getting current authorities' CFE versions.
 		type AuthoritiesCfeVersions: AuthoritiesCfeVersions;
+		/// The minimum number of governance members required to change the membership or to
+		/// execute proposals. A chain launched with fewer members executes proposals until the
+		/// minimum is first reached.
+		#[pallet::constant]
+		type MinimumMembers: Get<u32>;
+		/// The maximum number of proposals that can be active, ie. awaiting approval and not
+		/// expired, at the same time.
+		#[pallet::constant]
+		type MaxActiveProposals: Get<u32>;
+		/// The maximum number of active proposals a single member can have submitted.
+		#[pallet::constant]
+		type MaxProposalsPerMember: Get<u32>;
 	}
 
 	#[pallet::pallet]
@@ -132,7 +147,8 @@ pub mod pallet {
 	/// Active proposals.
 	#[pallet::storage]
 	#[pallet::getter(fn active_proposals)]
-	pub(super) type ActiveProposals<T> = StorageValue<_, Vec<ActiveProposal>, ValueQuery>;
+	pub(super) type ActiveProposals<T: Config> =
+		StorageValue<_, Vec<ActiveProposal<T::AccountId>>, ValueQuery>;
 
 	/// Call hash that has been committed to by the Governance Key.
 	#[pallet::storage]
@@ -170,6 +186,13 @@ pub mod pallet {
 	#[pallet::getter(fn members)]
 	pub(super) type Members<T> = StorageValue<_, BTreeSet<AccountId<T>>, ValueQuery>;
 
+	/// Set while a chain launched with fewer than [MinimumMembers](Config::MinimumMembers)
+	/// members, usually the root key alone, is yet to reach the minimum. Until then, proposals are
+	/// executed regardless of the size of the membership.
+	#[pallet::storage]
+	#[pallet::getter(fn bootstrapping)]
+	pub(super) type Bootstrapping<T> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// on_initialize hook - check the ActiveProposals
@@ -203,13 +226,20 @@ pub mod pallet {
 		GovKeyCallHashWhitelisted { call_hash: GovCallHash },
 		/// Failed GovKey call
 		GovKeyCallExecutionFailed { call_hash: GovCallHash, error: DispatchError },
+		/// Approvals of former members were withdrawn from a proposal \[proposal_id\]
+		ApprovalPruned(ProposalId),
+		/// An account was added to the governance set.
+		MemberAdded { account_id: T::AccountId },
+		/// An account was removed from the governance set.
+		MemberRemoved { account_id: T::AccountId },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// An account already approved a proposal
 		AlreadyApproved,
-		/// The signer of an extrinsic is no member of the current governance
+		/// The signer of an extrinsic, or the account to remove, is no member of the current
+		/// governance
 		NotMember,
 		/// The proposal was not found - it may have expired or it may already be executed
 		ProposalNotFound,
@@ -223,6 +253,14 @@ pub mod pallet {
 		CallHashNotWhitelisted,
 		/// Insufficient number of CFEs are at the target version to receive the runtime upgrade.
 		NotEnoughAuthoritiesCfesAtTargetVersion,
+		/// The governance set has, or would have, fewer than the minimum number of members.
+		TooFewMembers,
+		/// The account to add is already a member of the current governance.
+		AlreadyMember,
+		/// The maximum number of active proposals has been reached.
+		TooManyActiveProposals,
+		/// The proposer already has the maximum number of active proposals.
+		ProposerQuotaExceeded,
 	}
 
 	#[pallet::call]
@@ -236,6 +274,8 @@ pub mod pallet {
 		/// ## Errors
 		///
 		/// - [NotMember](Error::NotMember)
+		/// - [TooManyActiveProposals](Error::TooManyActiveProposals)
+		/// - [ProposerQuotaExceeded](Error::ProposerQuotaExceeded)
 		#[pallet::call_index(0)]
 		#[pallet::weight((T::WeightInfo::propose_governance_extrinsic(), DispatchClass::Operational))]
 		pub fn propose_governance_extrinsic(
@@ -245,7 +285,24 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let account_id = ensure_governance_member!(origin);
 
-			let id = Self::push_proposal(call, execution);
+			let now = T::TimeSource::now().as_secs();
+			let active_proposals = ActiveProposals::<T>::get()
+				.into_iter()
+				.filter(|active_proposal| active_proposal.expiry_time > now)
+				.collect::<Vec<_>>();
+			ensure!(
+				active_proposals.len() < T::MaxActiveProposals::get() as usize,
+				Error::<T>::TooManyActiveProposals
+			);
+			ensure!(
+				active_proposals
+					.iter()
+					.filter(|active_proposal| active_proposal.proposer.as_ref() == Some(&account_id))
+					.count() < T::MaxProposalsPerMember::get() as usize,
+				Error::<T>::ProposerQuotaExceeded
+			);
+
+			let id = Self::push_proposal(account_id.clone(), call, execution);
 			Self::deposit_event(Event::Proposed(id));
 
 			Self::inner_approve(account_id, id)?;
@@ -257,23 +314,29 @@ pub mod pallet {
 		/// Sets a new set of governance members
 		/// **Can only be called via the Governance Origin**
 		///
-		/// Sets a new set of governance members. Note that this can be called with an empty vector
-		/// to remove the possibility to govern the chain at all.
+		/// Sets a new set of governance members. The new set must contain at least
+		/// [MinimumMembers](Config::MinimumMembers) members. Approvals given by removed members are
+		/// withdrawn from all active proposals.
 		///
 		/// ## Events
 		///
-		/// - None
+		/// - [ApprovalPruned](Event::ApprovalPruned)
 		///
 		/// ## Errors
 		///
 		/// - [BadOrigin](frame_support::error::BadOrigin)
+		/// - [TooFewMembers](Error::TooFewMembers)
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::new_membership_set())]
+		#[pallet::weight(Pallet::<T>::new_membership_set_weight(T::MaxActiveProposals::get()))]
 		pub fn new_membership_set(
 			origin: OriginFor<T>,
 			new_members: BTreeSet<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			T::EnsureGovernance::ensure_origin(origin)?;
+			ensure!(
+				new_members.len() >= T::MinimumMembers::get() as usize,
+				Error::<T>::TooFewMembers
+			);
 			Members::<T>::mutate(|old_members| {
 				for member in old_members.difference(&new_members) {
 					<frame_system::Pallet<T>>::dec_sufficients(member);
@@ -283,7 +346,9 @@ pub mod pallet {
 				}
 				*old_members = new_members;
 			});
-			Ok(().into())
+			Bootstrapping::<T>::kill();
+			let active_proposals = Self::on_membership_changed();
+			Ok(Some(Self::new_membership_set_weight(active_proposals)).into())
 		}
 
 		/// Performs a runtime upgrade of the Chainflip runtime
@@ -439,6 +504,7 @@ pub mod pallet {
 			approved_id: ProposalId,
 		) -> DispatchResult {
 			ensure_governance_member!(origin);
+			Self::ensure_enough_members()?;
 			if let Some(call) = PreAuthorisedGovCalls::<T>::take(approved_id) {
 				if let Ok(call) = <T as Config>::RuntimeCall::decode(&mut &(*call)) {
 					Self::deposit_event(match Self::dispatch_governance_call(call) {
@@ -453,6 +519,68 @@ pub mod pallet {
 				Err(Error::<T>::ProposalNotFound.into())
 			}
 		}
+
+		/// Adds a single account to the set of governance members.
+		/// **Can only be called via the Governance Origin**
+		///
+		/// ## Events
+		///
+		/// - [MemberAdded](Event::MemberAdded)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		/// - [AlreadyMember](Error::AlreadyMember)
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::add_member())]
+		pub fn add_member(origin: OriginFor<T>, account_id: T::AccountId) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			Members::<T>::try_mutate(|members| {
+				ensure!(members.insert(account_id.clone()), Error::<T>::AlreadyMember);
+				if members.len() >= T::MinimumMembers::get() as usize {
+					Bootstrapping::<T>::kill();
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+			<frame_system::Pallet<T>>::inc_sufficients(&account_id);
+			Self::deposit_event(Event::MemberAdded { account_id });
+			Ok(())
+		}
+
+		/// Removes a single account from the set of governance members. Any approvals the account
+		/// has given to active proposals are withdrawn.
+		/// **Can only be called via the Governance Origin**
+		///
+		/// ## Events
+		///
+		/// - [MemberRemoved](Event::MemberRemoved)
+		/// - [ApprovalPruned](Event::ApprovalPruned)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		/// - [NotMember](Error::NotMember)
+		/// - [TooFewMembers](Error::TooFewMembers)
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::remove_member(T::MaxActiveProposals::get()))]
+		pub fn remove_member(
+			origin: OriginFor<T>,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			Members::<T>::try_mutate(|members| {
+				ensure!(members.remove(&account_id), Error::<T>::NotMember);
+				ensure!(
+					members.len() >= T::MinimumMembers::get() as usize,
+					Error::<T>::TooFewMembers
+				);
+				Ok::<_, Error<T>>(())
+			})?;
+			<frame_system::Pallet<T>>::dec_sufficients(&account_id);
+			let active_proposals = Self::on_membership_changed();
+			Self::deposit_event(Event::MemberRemoved { account_id });
+			Ok(Some(T::WeightInfo::remove_member(active_proposals)).into())
+		}
 	}
 
 	/// Genesis definition
@@ -477,6 +605,7 @@ pub mod pallet {
 				<frame_system::Pallet<T>>::inc_sufficients(member);
 			}
 			Members::<T>::set(self.members.clone());
+			Bootstrapping::<T>::set(self.members.len() < T::MinimumMembers::get() as usize);
 			ExpiryTime::<T>::set(self.expiry_span);
 		}
 	}
@@ -532,9 +661,22 @@ impl<T: Config> Pallet<T> {
 			Ok(proposal.clone())
 		})?;
 
-		if proposal.approved.len() >
-			(Members::<T>::decode_non_dedup_len().ok_or(Error::<T>::DecodeMembersLenFailed)? / 2)
-		{
+		Self::enqueue_if_majority_reached(
+			approved_id,
+			proposal,
+			Members::<T>::decode_non_dedup_len().ok_or(Error::<T>::DecodeMembersLenFailed)?,
+		);
+		Ok(())
+	}
+
+	/// Queues the proposal for execution and removes it from the active proposals if more than
+	/// half of the members approved it. Returns whether the proposal was queued.
+	fn enqueue_if_majority_reached(
+		approved_id: ProposalId,
+		proposal: Proposal<T::AccountId>,
+		member_count: usize,
+	) -> bool {
+		if proposal.approved.len() > member_count / 2 {
 			if proposal.execution == ExecutionMode::Manual {
 				PreAuthorisedGovCalls::<T>::insert(approved_id, proposal.call);
 			} else {
@@ -544,8 +686,40 @@ impl<T: Config> Pallet<T> {
 			ActiveProposals::<T>::mutate(|proposals| {
 				proposals.retain(|ActiveProposal { proposal_id, .. }| *proposal_id != approved_id)
 			});
+			true
+		} else {
+			false
 		}
-		Ok(())
+	}
+
+	fn new_membership_set_weight(active_proposals: u32) -> Weight {
+		T::WeightInfo::new_membership_set().saturating_add(
+			T::DbWeight::get().reads_writes(active_proposals as u64, active_proposals as u64),
+		)
+	}
+
+	/// Withdraws approvals given by accounts that are no longer members from all active
+	/// proposals, and re-evaluates whether each proposal has reached a majority of the new set.
+	/// Returns the number of active proposals, for weight accounting.
+	fn on_membership_changed() -> u32 {
+		let members = Members::<T>::get();
+		let active_proposals = ActiveProposals::<T>::get();
+		for &ActiveProposal { proposal_id, .. } in &active_proposals {
+			if let Some(mut proposal) = Proposals::<T>::get(proposal_id) {
+				let approval_count = proposal.approved.len();
+				proposal.approved.retain(|approver| members.contains(approver));
+				let pruned = proposal.approved.len() < approval_count;
+				if pruned {
+					Self::deposit_event(Event::ApprovalPruned(proposal_id));
+				}
+				if !Self::enqueue_if_majority_reached(proposal_id, proposal.clone(), members.len()) &&
+					pruned
+				{
+					Proposals::<T>::insert(proposal_id, proposal);
+				}
+			}
+		}
+		active_proposals.len() as u32
 	}
 
 	pub fn compute_gov_key_call_hash<CallData>(data: CallData) -> (GovCallHash, u32)
@@ -562,8 +736,8 @@ impl<T: Config> Pallet<T> {
 		if num_proposals == 0 {
 			return T::WeightInfo::on_initialize_best_case()
 		}
-		let (expired, active): (Vec<ActiveProposal>, Vec<ActiveProposal>) =
-			active_proposals.iter().partition(|active_proposal| {
+		let (expired, active): (Vec<_>, Vec<_>) =
+			active_proposals.into_iter().partition(|active_proposal| {
 				active_proposal.expiry_time <= T::TimeSource::now().as_secs()
 			});
 
@@ -571,25 +745,35 @@ impl<T: Config> Pallet<T> {
 		Self::expire_proposals(expired) + T::WeightInfo::on_initialize(num_proposals as u32)
 	}
 
+	fn ensure_enough_members() -> Result<(), Error<T>> {
+		ensure!(
+			Bootstrapping::<T>::get() ||
+				Members::<T>::decode_non_dedup_len().unwrap_or_default() >=
+					T::MinimumMembers::get() as usize,
+			Error::<T>::TooFewMembers
+		);
+		Ok(())
+	}
+
 	fn execute_pending_proposals() -> Weight {
 		let mut execution_weight = Weight::zero();
 		for (call, id) in ExecutionPipeline::<T>::take() {
-			Self::deposit_event(
-				if let Ok(call) = <T as Config>::RuntimeCall::decode(&mut &(*call)) {
-					execution_weight.saturating_accrue(call.get_dispatch_info().weight);
-					match Self::dispatch_governance_call(call) {
-						Ok(_) => Event::Executed(id),
-						Err(err) => Event::FailedExecution(err.error),
-					}
-				} else {
-					Event::DecodeOfCallFailed(id)
-				},
-			)
+			Self::deposit_event(if let Err(err) = Self::ensure_enough_members() {
+				Event::FailedExecution(err.into())
+			} else if let Ok(call) = <T as Config>::RuntimeCall::decode(&mut &(*call)) {
+				execution_weight.saturating_accrue(call.get_dispatch_info().weight);
+				match Self::dispatch_governance_call(call) {
+					Ok(_) => Event::Executed(id),
+					Err(err) => Event::FailedExecution(err.error),
+				}
+			} else {
+				Event::DecodeOfCallFailed(id)
+			})
 		}
 		execution_weight
 	}
 
-	fn expire_proposals(expired: Vec<ActiveProposal>) -> Weight {
+	fn expire_proposals(expired: Vec<ActiveProposal<T::AccountId>>) -> Weight {
 		for ActiveProposal { proposal_id, .. } in &expired {
 			Proposals::<T>::remove(proposal_id);
 			Self::deposit_event(Event::Expired(*proposal_id));
@@ -597,7 +781,11 @@ impl<T: Config> Pallet<T> {
 		T::WeightInfo::expire_proposals(expired.len() as u32)
 	}
 
-	fn push_proposal(call: Box<<T as Config>::RuntimeCall>, execution: ExecutionMode) -> u32 {
+	fn push_proposal(
+		proposer: T::AccountId,
+		call: Box<<T as Config>::RuntimeCall>,
+		execution: ExecutionMode,
+	) -> u32 {
 		let proposal_id = ProposalIdCounter::<T>::get().add(1);
 		Proposals::<T>::insert(
 			proposal_id,
@@ -607,6 +795,7 @@ impl<T: Config> Pallet<T> {
 		ActiveProposals::<T>::append(ActiveProposal {
 			proposal_id,
 			expiry_time: T::TimeSource::now().as_secs() + ExpiryTime::<T>::get(),
+			proposer: Some(proposer),
 		});
 		proposal_id
 	}
@@ -1,4 +1,11 @@
 use crate::Pallet;
-use cf_runtime_upgrade_utilities::PlaceholderMigration;
+use cf_runtime_upgrade_utilities::{PlaceholderMigration, VersionedMigration};
 
-pub type PalletMigration<T> = PlaceholderMigration<Pallet<T>, 2>;
+pub mod add_proposer;
+pub mod initialize_bootstrapping;
+
+pub type PalletMigration<T> = (
+	VersionedMigration<Pallet<T>, add_proposer::Migration<T>, 2, 3>,
+	VersionedMigration<Pallet<T>, initialize_bootstrapping::Migration<T>, 3, 4>,
+	PlaceholderMigration<Pallet<T>, 4>,
+);
@@ -0,0 +1,75 @@
+use crate::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+
+mod old {
+	use super::*;
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+	pub struct ActiveProposal {
+		pub proposal_id: ProposalId,
+		pub expiry_time: u64,
+	}
+
+	#[frame_support::storage_alias]
+	pub type ActiveProposals<T: Config> = StorageValue<Pallet<T>, Vec<ActiveProposal>, ValueQuery>;
+}
+
+pub struct Migration<T: Config>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// The proposer of existing proposals is unknown, so they don't count towards any member's
+		// quota.
+		let _ = ActiveProposals::<T>::translate::<Vec<old::ActiveProposal>, _>(|old_proposals| {
+			old_proposals.map(|old_proposals| {
+				old_proposals
+					.into_iter()
+					.map(|old::ActiveProposal { proposal_id, expiry_time }| ActiveProposal {
+						proposal_id,
+						expiry_time,
+						proposer: None,
+					})
+					.collect()
+			})
+		});
+
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod migration_tests {
+	use super::*;
+	use crate::mock::*;
+
+	#[test]
+	fn test_migration() {
+		new_test_ext().execute_with(|| {
+			old::ActiveProposals::<Test>::put(vec![
+				old::ActiveProposal { proposal_id: 1, expiry_time: 100 },
+				old::ActiveProposal { proposal_id: 2, expiry_time: 200 },
+			]);
+
+			super::Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				ActiveProposals::<Test>::get(),
+				vec![
+					ActiveProposal { proposal_id: 1, expiry_time: 100, proposer: None },
+					ActiveProposal { proposal_id: 2, expiry_time: 200, proposer: None },
+				]
+			);
+		});
+	}
+}
@@ -0,0 +1,47 @@
+use crate::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+
+pub struct Migration<T: Config>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// Networks launched with the root key as their only member would otherwise be unable to
+		// execute the proposals that grow the membership to the minimum.
+		Bootstrapping::<T>::set(
+			Members::<T>::decode_non_dedup_len().unwrap_or_default() <
+				T::MinimumMembers::get() as usize,
+		);
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod migration_tests {
+	use super::*;
+	use crate::mock::*;
+	use sp_std::collections::btree_set::BTreeSet;
+
+	#[test]
+	fn test_migration() {
+		new_test_ext().execute_with(|| {
+			super::Migration::<Test>::on_runtime_upgrade();
+			assert!(!Bootstrapping::<Test>::get());
+
+			Members::<Test>::put(BTreeSet::from([ALICE]));
+			super::Migration::<Test>::on_runtime_upgrade();
+			assert!(Bootstrapping::<Test>::get());
+		});
+	}
+}
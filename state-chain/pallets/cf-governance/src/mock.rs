@@ -6,7 +6,9 @@ use cf_traits::{
 	impl_mock_chainflip, mocks::time_source, AuthoritiesCfeVersions, CompatibleCfeVersions,
 	ExecutionCondition, RuntimeUpgrade,
 };
-use frame_support::{derive_impl, dispatch::DispatchResultWithPostInfo, ensure, parameter_types};
+use frame_support::{
+	derive_impl, dispatch::DispatchResultWithPostInfo, ensure, parameter_types, traits::ConstU32,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
@@ -127,6 +129,9 @@ impl pallet_cf_governance::Config for Test {
 	type RuntimeUpgrade = RuntimeUpgradeMock;
 	type AuthoritiesCfeVersions = MockAuthoritiesCfeVersions;
 	type CompatibleCfeVersions = MockCompatibleCfeVersions;
+	type MinimumMembers = ConstU32<3>;
+	type MaxActiveProposals = ConstU32<5>;
+	type MaxProposalsPerMember = ConstU32<3>;
 }
 
 pub const ALICE: <Test as frame_system::Config>::AccountId = 123u64;
@@ -1,11 +1,11 @@
 use crate::{
-	mock::*, ActiveProposals, Error, ExecutionMode, ExecutionPipeline, ExpiryTime, Members,
-	PreAuthorisedGovCalls, ProposalIdCounter,
+	mock::*, ActiveProposals, Bootstrapping, Error, ExecutionMode, ExecutionPipeline, ExpiryTime,
+	Members, PreAuthorisedGovCalls, ProposalIdCounter,
 };
 use cf_primitives::SemVer;
 use cf_test_utilities::last_event;
 use cf_traits::mocks::time_source;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, traits::Get};
 use sp_runtime::Percent;
 use sp_std::collections::btree_set::BTreeSet;
 use std::time::Duration;
@@ -146,6 +146,76 @@ fn propose_a_governance_extrinsic_and_expect_it_to_expire() {
 		});
 }
 
+#[test]
+fn number_of_active_proposals_is_limited() {
+	const START_TIME: Duration = Duration::from_secs(10);
+	const END_TIME: Duration = Duration::from_secs(7300);
+
+	new_test_ext()
+		.execute_with(|| {
+			time_source::Mock::reset_to(START_TIME);
+			// Spread the proposals over the members to stay within each member's quota.
+			for proposer in [ALICE, BOB, CHARLES]
+				.into_iter()
+				.cycle()
+				.take(<Test as pallet_cf_governance::Config>::MaxActiveProposals::get() as usize)
+			{
+				assert_ok!(Governance::propose_governance_extrinsic(
+					RuntimeOrigin::signed(proposer),
+					mock_extrinsic(),
+					ExecutionMode::Automatic,
+				));
+			}
+			assert_noop!(
+				Governance::propose_governance_extrinsic(
+					RuntimeOrigin::signed(CHARLES),
+					mock_extrinsic(),
+					ExecutionMode::Automatic,
+				),
+				<Error<Test>>::TooManyActiveProposals
+			);
+
+			// Expired proposals don't count towards the limit, even before they are removed.
+			time_source::Mock::reset_to(END_TIME);
+			assert_ok!(Governance::propose_governance_extrinsic(
+				RuntimeOrigin::signed(BOB),
+				mock_extrinsic(),
+				ExecutionMode::Automatic,
+			));
+		})
+		.then_execute_at_next_block(|_| {
+			assert_eq!(ActiveProposals::<Test>::get().len(), 1);
+		});
+}
+
+#[test]
+fn number_of_active_proposals_per_member_is_limited() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..<Test as pallet_cf_governance::Config>::MaxProposalsPerMember::get() {
+			assert_ok!(Governance::propose_governance_extrinsic(
+				RuntimeOrigin::signed(ALICE),
+				mock_extrinsic(),
+				ExecutionMode::Automatic,
+			));
+		}
+		assert_noop!(
+			Governance::propose_governance_extrinsic(
+				RuntimeOrigin::signed(ALICE),
+				mock_extrinsic(),
+				ExecutionMode::Automatic,
+			),
+			<Error<Test>>::ProposerQuotaExceeded
+		);
+
+		// Other members can still propose.
+		assert_ok!(Governance::propose_governance_extrinsic(
+			RuntimeOrigin::signed(BOB),
+			mock_extrinsic(),
+			ExecutionMode::Automatic,
+		));
+	});
+}
+
 #[test]
 fn can_not_vote_twice() {
 	new_test_ext().execute_with(|| {
@@ -357,3 +427,201 @@ fn replacing_governance_members() {
 		assert_eq!(System::sufficients(&MAX), 0);
 	});
 }
+
+#[test]
+fn membership_set_cannot_be_smaller_than_minimum() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Governance::new_membership_set(
+				crate::RawOrigin::GovernanceApproval.into(),
+				BTreeSet::from_iter([EVE, PETER])
+			),
+			<Error<Test>>::TooFewMembers
+		);
+	});
+}
+
+#[test]
+fn proposals_are_not_executed_with_too_few_members() {
+	new_test_ext()
+		.execute_with(|| {
+			assert_ok!(Governance::propose_governance_extrinsic(
+				RuntimeOrigin::signed(ALICE),
+				mock_extrinsic(),
+				ExecutionMode::Manual,
+			));
+			assert_ok!(Governance::approve(RuntimeOrigin::signed(BOB), 1));
+			assert!(PreAuthorisedGovCalls::<Test>::contains_key(1));
+
+			Members::<Test>::put(BTreeSet::from_iter([ALICE]));
+
+			assert_noop!(
+				Governance::dispatch_whitelisted_call(RuntimeOrigin::signed(ALICE), 1),
+				<Error<Test>>::TooFewMembers
+			);
+
+			// A single member can push a proposal into the execution pipeline on their own...
+			assert_ok!(Governance::propose_governance_extrinsic(
+				RuntimeOrigin::signed(ALICE),
+				mock_extrinsic(),
+				ExecutionMode::Automatic,
+			));
+			assert_eq!(ExecutionPipeline::<Test>::decode_len(), Some(1));
+		})
+		.then_execute_at_next_block(|_| {
+			// ...but it is not executed.
+			assert_eq!(
+				last_event::<Test>(),
+				crate::mock::RuntimeEvent::Governance(crate::Event::FailedExecution(
+					Error::<Test>::TooFewMembers.into()
+				)),
+			);
+			assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([ALICE]));
+		});
+}
+
+#[test]
+fn proposals_are_executed_while_bootstrapping_a_root_key_launch() {
+	new_test_ext()
+		.execute_with(|| {
+			Members::<Test>::put(BTreeSet::from_iter([ALICE]));
+			Bootstrapping::<Test>::put(true);
+
+			assert_ok!(Governance::propose_governance_extrinsic(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(RuntimeCall::Governance(
+					pallet_cf_governance::Call::<Test>::new_membership_set {
+						new_members: BTreeSet::from_iter([ALICE, BOB, CHARLES]),
+					}
+				)),
+				ExecutionMode::Automatic,
+			));
+		})
+		.then_execute_at_next_block(|_| {
+			assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([ALICE, BOB, CHARLES]));
+			assert!(!Bootstrapping::<Test>::get());
+
+			// Once the minimum has been reached, it is enforced.
+			Members::<Test>::put(BTreeSet::from_iter([ALICE]));
+			assert_ok!(Governance::propose_governance_extrinsic(
+				RuntimeOrigin::signed(ALICE),
+				mock_extrinsic(),
+				ExecutionMode::Automatic,
+			));
+		})
+		.then_execute_at_next_block(|_| {
+			assert_eq!(
+				last_event::<Test>(),
+				crate::mock::RuntimeEvent::Governance(crate::Event::FailedExecution(
+					Error::<Test>::TooFewMembers.into()
+				)),
+			);
+		});
+}
+
+#[test]
+fn adding_and_removing_governance_members() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::add_member(crate::RawOrigin::GovernanceApproval.into(), EVE));
+		assert_eq!(
+			last_event::<Test>(),
+			crate::mock::RuntimeEvent::Governance(crate::Event::MemberAdded { account_id: EVE }),
+		);
+		assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([ALICE, BOB, CHARLES, EVE]));
+		assert_eq!(System::sufficients(&EVE), 1);
+		assert_noop!(
+			Governance::add_member(crate::RawOrigin::GovernanceApproval.into(), EVE),
+			<Error<Test>>::AlreadyMember
+		);
+
+		assert_ok!(Governance::remove_member(crate::RawOrigin::GovernanceApproval.into(), EVE));
+		assert_eq!(
+			last_event::<Test>(),
+			crate::mock::RuntimeEvent::Governance(crate::Event::MemberRemoved { account_id: EVE }),
+		);
+		assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([ALICE, BOB, CHARLES]));
+		assert_eq!(System::sufficients(&EVE), 0);
+		assert_noop!(
+			Governance::remove_member(crate::RawOrigin::GovernanceApproval.into(), EVE),
+			<Error<Test>>::NotMember
+		);
+		assert_noop!(
+			Governance::remove_member(crate::RawOrigin::GovernanceApproval.into(), ALICE),
+			<Error<Test>>::TooFewMembers
+		);
+	});
+}
+
+#[test]
+fn removed_member_approvals_are_withdrawn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::add_member(crate::RawOrigin::GovernanceApproval.into(), EVE));
+		assert_ok!(Governance::propose_governance_extrinsic(
+			RuntimeOrigin::signed(ALICE),
+			mock_extrinsic(),
+			ExecutionMode::Automatic,
+		));
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(EVE), 1));
+		assert_eq!(Governance::proposals(1).unwrap().approved, BTreeSet::from_iter([ALICE, EVE]));
+
+		assert_ok!(Governance::remove_member(crate::RawOrigin::GovernanceApproval.into(), EVE));
+
+		assert_eq!(Governance::proposals(1).unwrap().approved, BTreeSet::from_iter([ALICE]));
+		assert_noop!(Governance::approve(RuntimeOrigin::signed(EVE), 1), <Error<Test>>::NotMember);
+	});
+}
+
+#[test]
+fn replacing_members_prunes_their_approvals() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::new_membership_set(
+			crate::RawOrigin::GovernanceApproval.into(),
+			BTreeSet::from_iter([ALICE, BOB, CHARLES, EVE])
+		));
+		assert_ok!(Governance::propose_governance_extrinsic(
+			RuntimeOrigin::signed(ALICE),
+			mock_extrinsic(),
+			ExecutionMode::Automatic,
+		));
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(BOB), 1));
+
+		assert_ok!(Governance::new_membership_set(
+			crate::RawOrigin::GovernanceApproval.into(),
+			BTreeSet::from_iter([ALICE, CHARLES, EVE])
+		));
+
+		assert_eq!(
+			last_event::<Test>(),
+			crate::mock::RuntimeEvent::Governance(crate::Event::ApprovalPruned(1)),
+		);
+		assert_eq!(Governance::proposals(1).unwrap().approved, BTreeSet::from_iter([ALICE]));
+		assert!(ExecutionPipeline::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn shrinking_membership_can_complete_a_majority() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::new_membership_set(
+			crate::RawOrigin::GovernanceApproval.into(),
+			BTreeSet::from_iter([ALICE, BOB, CHARLES, EVE])
+		));
+		assert_ok!(Governance::propose_governance_extrinsic(
+			RuntimeOrigin::signed(ALICE),
+			mock_extrinsic(),
+			ExecutionMode::Automatic,
+		));
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(BOB), 1));
+		assert!(ExecutionPipeline::<Test>::get().is_empty());
+
+		// Two out of three remaining members approved, which is now a majority.
+		assert_ok!(Governance::new_membership_set(
+			crate::RawOrigin::GovernanceApproval.into(),
+			BTreeSet::from_iter([ALICE, BOB, CHARLES])
+		));
+
+		assert_eq!(ExecutionPipeline::<Test>::decode_len(), Some(1));
+		assert!(Governance::proposals(1).is_none());
+		assert!(ActiveProposals::<Test>::get().is_empty());
+	});
+}
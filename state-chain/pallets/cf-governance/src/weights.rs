@@ -42,6 +42,8 @@ pub trait WeightInfo {
 	fn set_whitelisted_call_hash() -> Weight;
 	fn submit_govkey_call() -> Weight;
 	fn dispatch_whitelisted_call() -> Weight;
+	fn add_member() -> Weight;
+	fn remove_member(b: u32, ) -> Weight;
 }
 
 /// Weights for pallet_cf_governance using the Substrate node and recommended hardware.
@@ -244,6 +246,18 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	fn add_member() -> Weight {
+		// Placeholder until the weights are regenerated from the `add_member` benchmark. Bounded by
+		// `new_membership_set`, which replaces the whole set.
+		Self::new_membership_set()
+	}
+	/// The range of component `b` is `[1, 100]`.
+	fn remove_member(b: u32, ) -> Weight {
+		// Placeholder until the weights are regenerated from the `remove_member` benchmark. Bounded
+		// by `new_membership_set`, plus an `approve` for each active proposal that is re-evaluated.
+		Self::new_membership_set()
+			.saturating_add(Self::approve().saturating_mul(b.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -445,4 +459,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	fn add_member() -> Weight {
+		// Placeholder until the weights are regenerated from the `add_member` benchmark. Bounded by
+		// `new_membership_set`, which replaces the whole set.
+		Self::new_membership_set()
+	}
+	/// The range of component `b` is `[1, 100]`.
+	fn remove_member(b: u32, ) -> Weight {
+		// Placeholder until the weights are regenerated from the `remove_member` benchmark. Bounded
+		// by `new_membership_set`, plus an `approve` for each active proposal that is re-evaluated.
+		Self::new_membership_set()
+			.saturating_add(Self::approve().saturating_mul(b.into()))
+	}
 }
@@ -671,6 +671,10 @@ pub mod pallet {
 		BoostPoolCreated {
 			boost_pool: BoostPoolId<T::TargetChain>,
 		},
+		/// A deposit channel has passed its recycle height and has been closed.
+		DepositChannelExpired {
+			channel_id: ChannelId,
+		},
 	}
 
 	#[derive(CloneNoBound, PartialEqNoBound, EqNoBound)]
@@ -750,6 +754,9 @@ pub mod pallet {
 				if let Some(DepositChannelDetails { deposit_channel, boost_status, .. }) =
 					DepositChannelLookup::<T, I>::take(address)
 				{
+					Self::deposit_event(Event::<T, I>::DepositChannelExpired {
+						channel_id: deposit_channel.channel_id,
+					});
 					if let Some(state) = deposit_channel.state.maybe_recycle() {
 						DepositChannelPool::<T, I>::insert(
 							deposit_channel.channel_id,
@@ -418,6 +418,26 @@ fn proof_address_pool_integrity() {
 	});
 }
 
+#[test]
+fn expired_deposit_channels_are_closed() {
+	new_test_ext().execute_with(|| {
+		let (channel_id, address) = request_address_and_deposit(ALICE, eth::Asset::Eth);
+		let (_, expiry_block, recycle_block) = IngressEgress::expiry_and_recycle_block_height();
+
+		// Expired channels are kept until they can be recycled.
+		BlockHeightProvider::<MockEthereum>::set_block_height(expiry_block);
+		IngressEgress::on_idle(1, Weight::MAX);
+		assert!(DepositChannelLookup::<Test, ()>::contains_key(address));
+
+		BlockHeightProvider::<MockEthereum>::set_block_height(recycle_block);
+		IngressEgress::on_idle(1, Weight::MAX);
+		assert!(!DepositChannelLookup::<Test, ()>::contains_key(address));
+		assert_has_event::<Test>(RuntimeEvent::IngressEgress(
+			crate::Event::DepositChannelExpired { channel_id },
+		));
+	});
+}
+
 #[test]
 fn create_new_address_while_pool_is_empty() {
 	new_test_ext().execute_with(|| {
@@ -8,7 +8,6 @@ use cf_traits::{AccountRoleRegistry, LpBalanceApi};
 use frame_benchmarking::v2::*;
 use frame_support::{
 	assert_ok,
-	sp_runtime::traits::One,
 	traits::{EnsureOrigin, UnfilteredDispatchable},
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
@@ -34,7 +33,8 @@ mod benchmarks {
 
 	#[benchmark]
 	fn update_buy_interval() {
-		let call = Call::<T>::update_buy_interval { new_buy_interval: BlockNumberFor::<T>::one() };
+		let call =
+			Call::<T>::update_buy_interval { new_buy_interval: T::MinFlipBuyInterval::get() };
 
 		#[block]
 		{
@@ -43,7 +43,7 @@ mod benchmarks {
 			);
 		}
 
-		assert_eq!(FlipBuyInterval::<T>::get(), BlockNumberFor::<T>::one());
+		assert_eq!(FlipBuyInterval::<T>::get(), T::MinFlipBuyInterval::get());
 	}
 
 	#[benchmark]
@@ -282,5 +282,58 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn set_minimum_pool_reserve() {
+		let minimum_reserve = PoolPairsMap { base: 1_000, quote: 1_000 };
+		let call = Call::<T>::set_minimum_pool_reserve {
+			base_asset: Asset::Eth,
+			quote_asset: Asset::Usdc,
+			minimum_reserve,
+		};
+
+		#[block]
+		{
+			assert_ok!(
+				call.dispatch_bypass_filter(T::EnsureGovernance::try_successful_origin().unwrap())
+			);
+		}
+
+		assert_eq!(
+			MinimumPoolReserve::<T>::get(AssetPair::new(Asset::Eth, Asset::Usdc).unwrap()),
+			minimum_reserve
+		);
+	}
+
+	#[benchmark]
+	fn check_pool_invariant(n: Linear<1, 100>) {
+		let caller = new_lp_account::<T>();
+		assert_ok!(Pallet::<T>::new_pool(
+			T::EnsureGovernance::try_successful_origin().unwrap(),
+			Asset::Eth,
+			Asset::Usdc,
+			0,
+			price_at_tick(0).unwrap()
+		));
+		assert_ok!(T::LpBalance::try_credit_account(&caller, Asset::Eth, 1_000_000,));
+		for id in 0..n {
+			assert_ok!(Pallet::<T>::set_limit_order(
+				RawOrigin::Signed(caller.clone()).into(),
+				Asset::Eth,
+				Asset::Usdc,
+				Side::Sell,
+				id.into(),
+				Some(id as Tick),
+				1_000,
+			));
+		}
+		let asset_pair = AssetPair::new(Asset::Eth, Asset::Usdc).unwrap();
+		let pool = Pools::<T>::get(asset_pair).unwrap();
+
+		#[block]
+		{
+			assert_eq!(Pallet::<T>::pool_matches_ledger(&asset_pair, &pool), (true, n));
+		}
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
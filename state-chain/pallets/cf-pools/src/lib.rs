@@ -2,7 +2,9 @@
 use core::ops::Range;
 
 use cf_amm::{
-	common::{self, Amount, PoolPairsMap, Price, Side, SqrtPriceQ64F96, Tick},
+	common::{
+		self, Amount, Pairs, PoolPairsMap, Price, Side, SqrtPriceQ64F96, Tick, MAX_TICK, MIN_TICK,
+	},
 	limit_orders::{self, Collected, PositionInfo},
 	range_orders::{self, Liquidity},
 	PoolState,
@@ -121,7 +123,16 @@ impl<T> AskBidMap<T> {
 	}
 }
 
-pub const PALLET_VERSION: StorageVersion = StorageVersion::new(4);
+pub const PALLET_VERSION: StorageVersion = StorageVersion::new(5);
+
+/// Number of blocks between checks that the assets a pool owes to its liquidity providers are
+/// consistent with the pool's ledger. Each check covers a single pool, taking the pools in turn.
+pub const POOL_INVARIANT_CHECK_INTERVAL: u32 = 100;
+
+/// How far, relative to the ledger, a pool's liabilities may diverge from its ledger before a
+/// [PoolInvariantViolation](Event::PoolInvariantViolation) is reported. A further unit of each
+/// asset is allowed per open order to account for rounding.
+pub const POOL_INVARIANT_TOLERANCE: Permill = Permill::from_percent(1);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -262,6 +273,22 @@ pub mod pallet {
 		#[pallet::constant]
 		type NetworkFee: Get<Permill>;
 
+		/// The shortest allowed interval, in blocks, at which FLIP is bought for burning.
+		#[pallet::constant]
+		type MinFlipBuyInterval: Get<BlockNumberFor<Self>>;
+
+		/// The longest allowed interval, in blocks, at which FLIP is bought for burning.
+		#[pallet::constant]
+		type MaxFlipBuyInterval: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks aggregated into a single bucket of the swap volume history.
+		#[pallet::constant]
+		type SwapVolumeBucketSize: Get<BlockNumberFor<Self>>;
+
+		/// The number of buckets kept in each asset's swap volume history.
+		#[pallet::constant]
+		type MaxSwapVolumeHistory: Get<u32>;
+
 		/// Safe Mode access.
 		type SafeMode: Get<PalletSafeMode>;
 
@@ -282,6 +309,10 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type FlipBuyInterval<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+	/// The last block at which collected network fees were swapped to FLIP for burning.
+	#[pallet::storage]
+	pub type LastFlipBuyBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	/// Network fees, in USDC terms, that have been collected and are ready to be converted to FLIP.
 	#[pallet::storage]
 	pub type CollectedNetworkFee<T: Config> = StorageValue<_, AssetAmount, ValueQuery>;
@@ -297,6 +328,42 @@ pub mod pallet {
 	pub(super) type MaximumPriceImpact<T: Config> =
 		StorageMap<_, Twox64Concat, AssetPair, u32, OptionQuery>;
 
+	/// The minimum amount of each of a pool's assets that must remain available in the pool after a
+	/// swap that buys that asset. Swaps that would leave less than this amount are rejected.
+	#[pallet::storage]
+	pub type MinimumPoolReserve<T: Config> =
+		StorageMap<_, Twox64Concat, AssetPair, PoolPairsMap<AssetAmount>, ValueQuery>;
+
+	/// The net amount of each asset paid into each pool: LP deposits and swap inputs, less LP
+	/// withdrawals and payouts and swap outputs.
+	#[pallet::storage]
+	pub type PoolLedger<T: Config> =
+		StorageMap<_, Twox64Concat, AssetPair, PoolPairsMap<AssetAmount>, ValueQuery>;
+
+	/// The pool most recently checked against its ledger. The next check takes the pool after it.
+	#[pallet::storage]
+	pub(super) type LastCheckedPool<T: Config> = StorageValue<_, AssetPair, OptionQuery>;
+
+	/// Cumulative swap input volume for each asset, denominated in the asset itself.
+	#[pallet::storage]
+	pub type SwapVolume<T: Config> = StorageMap<_, Twox64Concat, Asset, AssetAmount, ValueQuery>;
+
+	/// Swap input volume for each asset, aggregated into buckets of `SwapVolumeBucketSize` blocks.
+	///
+	/// The buckets form a ring buffer of `MaxSwapVolumeHistory` slots per asset, so recording a
+	/// swap only touches a single entry. Each entry holds the first block of its bucket, which is
+	/// used to detect (and overwrite) entries that have fallen out of the window.
+	#[pallet::storage]
+	pub type SwapVolumeHistory<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		Asset,
+		Twox64Concat,
+		u32,
+		(BlockNumberFor<T>, AssetAmount),
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub flip_buy_interval: BlockNumberFor<T>,
@@ -305,6 +372,12 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
+			assert!(
+				self.flip_buy_interval.is_zero() ||
+					(T::MinFlipBuyInterval::get()..=T::MaxFlipBuyInterval::get())
+						.contains(&self.flip_buy_interval),
+				"Flip buy interval must be zero or within the allowed bounds"
+			);
 			FlipBuyInterval::<T>::set(self.flip_buy_interval);
 		}
 	}
@@ -327,7 +400,7 @@ pub mod pallet {
 				if (current_block % interval).is_zero() &&
 					!CollectedNetworkFee::<T>::get().is_zero()
 				{
-					weight_used.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+					weight_used.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 					if let Err(e) = CollectedNetworkFee::<T>::try_mutate(|collected_fee| {
 						T::SwapQueueApi::schedule_swap(
 							any::Asset::Usdc,
@@ -336,6 +409,7 @@ pub mod pallet {
 							SwapType::NetworkFee,
 						);
 						collected_fee.set_zero();
+						LastFlipBuyBlock::<T>::put(current_block);
 						Ok::<_, DispatchError>(())
 					}) {
 						log::warn!("Unable to swap Network Fee to Flip: {e:?}");
@@ -366,6 +440,22 @@ pub mod pallet {
 				});
 				weight_used.saturating_accrue(call_weight);
 			}
+
+			if (current_block % BlockNumberFor::<T>::from(POOL_INVARIANT_CHECK_INTERVAL)).is_zero()
+			{
+				weight_used.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+				if let Some((asset_pair, pool)) = Self::next_pool_to_check() {
+					let (matches_ledger, order_count) =
+						Self::pool_matches_ledger(&asset_pair, &pool);
+					weight_used.saturating_accrue(T::WeightInfo::check_pool_invariant(order_count));
+					if !matches_ledger {
+						log::error!("Liabilities of pool {asset_pair:?} diverge from its ledger.");
+						Self::deposit_event(Event::<T>::PoolInvariantViolation { asset_pair });
+					}
+					LastCheckedPool::<T>::put(asset_pair);
+				}
+			}
+
 			weight_used
 		}
 	}
@@ -374,6 +464,10 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Setting the buy interval to zero is not allowed.
 		ZeroBuyIntervalNotAllowed,
+		/// The buy interval is shorter than the minimum allowed interval.
+		BuyIntervalTooShort,
+		/// The buy interval is longer than the maximum allowed interval.
+		BuyIntervalTooLong,
 		/// The specified exchange pool already exists.
 		PoolAlreadyExists,
 		/// The specified exchange pool does not exist.
@@ -413,6 +507,8 @@ pub mod pallet {
 		UnsupportedCall,
 		/// The update can't be scheduled because it has expired (dispatch_at is in the past).
 		LimitOrderUpdateExpired,
+		/// The swap would leave less than the minimum reserve of the bought asset in the pool.
+		InsufficientPoolLiquidity,
 	}
 
 	#[pallet::event]
@@ -488,6 +584,16 @@ pub mod pallet {
 			asset_pair: AssetPair,
 			limit: Option<u32>,
 		},
+		/// The periodic self-check found that the assets a pool owes to its liquidity providers
+		/// diverge from the pool's ledger.
+		PoolInvariantViolation {
+			asset_pair: AssetPair,
+		},
+		/// The minimum reserves have been set for a pool.
+		MinimumPoolReserveSet {
+			asset_pair: AssetPair,
+			minimum_reserve: PoolPairsMap<AssetAmount>,
+		},
 	}
 
 	#[pallet::call]
@@ -502,6 +608,8 @@ pub mod pallet {
 		///
 		/// - [BadOrigin](frame_system::BadOrigin)
 		/// - [ZeroBuyIntervalNotAllowed](pallet_cf_pools::Error::ZeroBuyIntervalNotAllowed)
+		/// - [BuyIntervalTooShort](pallet_cf_pools::Error::BuyIntervalTooShort)
+		/// - [BuyIntervalTooLong](pallet_cf_pools::Error::BuyIntervalTooLong)
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::update_buy_interval())]
 		pub fn update_buy_interval(
@@ -510,6 +618,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::EnsureGovernance::ensure_origin(origin)?;
 			ensure!(new_buy_interval != Zero::zero(), Error::<T>::ZeroBuyIntervalNotAllowed);
+			ensure!(
+				new_buy_interval >= T::MinFlipBuyInterval::get(),
+				Error::<T>::BuyIntervalTooShort
+			);
+			ensure!(
+				new_buy_interval <= T::MaxFlipBuyInterval::get(),
+				Error::<T>::BuyIntervalTooLong
+			);
 			FlipBuyInterval::<T>::set(new_buy_interval);
 			Self::deposit_event(Event::<T>::UpdatedBuyInterval { buy_interval: new_buy_interval });
 			Ok(())
@@ -1001,6 +1117,36 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets the minimum amount of each of the pool's assets that must remain in the pool after
+		/// a swap buying that asset. Swaps that would take the pool's available liquidity below
+		/// this amount fail with [InsufficientPoolLiquidity](Error::InsufficientPoolLiquidity), and
+		/// will be retried in the next block.
+		///
+		/// Setting a minimum to zero disables the check for that asset.
+		///
+		/// ## Events
+		///
+		/// - [MinimumPoolReserveSet](Event::MinimumPoolReserveSet)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [PoolDoesNotExist](pallet_cf_pools::Error::PoolDoesNotExist)
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_minimum_pool_reserve())]
+		pub fn set_minimum_pool_reserve(
+			origin: OriginFor<T>,
+			base_asset: Asset,
+			quote_asset: Asset,
+			minimum_reserve: PoolPairsMap<AssetAmount>,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			let asset_pair = AssetPair::try_new::<T>(base_asset, quote_asset)?;
+			MinimumPoolReserve::<T>::insert(asset_pair, minimum_reserve);
+			Self::deposit_event(Event::<T>::MinimumPoolReserveSet { asset_pair, minimum_reserve });
+			Ok(())
+		}
 	}
 }
 
@@ -1016,6 +1162,12 @@ impl<T: Config> SwappingApi for Pallet<T> {
 		NetworkFeeTaken { remaining_amount: remaining, network_fee: fee }
 	}
 
+	fn swap_single_leg_weight() -> Weight {
+		// `MinimumPoolReserve` and `PoolLedger` of the pool, and `SwapVolume` and the current
+		// `SwapVolumeHistory` bucket of the input asset.
+		T::DbWeight::get().reads_writes(4, 3)
+	}
+
 	#[transactional]
 	fn swap_single_leg(
 		from: any::Asset,
@@ -1066,8 +1218,29 @@ impl<T: Config> SwappingApi for Pallet<T> {
 					}
 				}
 
+				// Only the liquidity needed to cover the minimum is visited, rather than the whole
+				// book.
+				let minimum_reserve: Amount =
+					MinimumPoolReserve::<T>::get(asset_pair)[!order.to_sold_pair()].into();
+				if !minimum_reserve.is_zero() &&
+					pool.pool_state.available_output(order, minimum_reserve) < minimum_reserve
+				{
+					return Err(Error::<T>::InsufficientPoolLiquidity.into());
+				}
+
 				output_amount.try_into().map_err(|_| Error::<T>::OutputOverflow)?
 			};
+			Self::record_pool_flow(
+				&asset_pair,
+				order.to_sold_pair(),
+				IncreaseOrDecrease::Increase(input_amount),
+			);
+			Self::record_pool_flow(
+				&asset_pair,
+				!order.to_sold_pair(),
+				IncreaseOrDecrease::Decrease(output_amount),
+			);
+			Self::record_swap_volume(from, input_amount);
 			Self::deposit_event(Event::<T>::AssetSwapped { from, to, input_amount, output_amount });
 			Ok(output_amount)
 		})
@@ -1222,6 +1395,15 @@ pub struct PoolPriceV2 {
 	pub range_order: SqrtPriceQ64F96,
 }
 
+#[derive(Serialize, Deserialize, Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug)]
+pub struct PoolSummary {
+	pub base_asset: Asset,
+	pub quote_asset: Asset,
+	/// The total amount of each asset that can currently be bought from the pool.
+	pub reserves: PoolPairsMap<Amount>,
+	pub price: PoolPriceV2,
+}
+
 #[derive(PartialEq, Eq)]
 enum NoOpStatus {
 	Allow,
@@ -1346,6 +1528,11 @@ impl<T: Config> Pallet<T> {
 					asset_pair.assets()[side.to_sold_pair()],
 					debited_amount,
 				)?;
+				Self::record_pool_flow(
+					asset_pair,
+					side.to_sold_pair(),
+					IncreaseOrDecrease::Increase(debited_amount),
+				);
 
 				(IncreaseOrDecrease::Increase(debited_amount), position_info, collected)
 			},
@@ -1373,6 +1560,11 @@ impl<T: Config> Pallet<T> {
 					asset_pair.assets()[side.to_sold_pair()],
 					withdrawn_amount,
 				)?;
+				Self::record_pool_flow(
+					asset_pair,
+					side.to_sold_pair(),
+					IncreaseOrDecrease::Decrease(withdrawn_amount),
+				);
 
 				(IncreaseOrDecrease::Decrease(withdrawn_amount), position_info, collected)
 			},
@@ -1449,6 +1641,14 @@ impl<T: Config> Pallet<T> {
 						}),
 					}?;
 
+				for pair in [Pairs::Base, Pairs::Quote] {
+					Self::record_pool_flow(
+						asset_pair,
+						pair,
+						IncreaseOrDecrease::Increase(assets_debited[pair]),
+					);
+				}
+
 				(
 					IncreaseOrDecrease::Increase(minted_liquidity),
 					position_info,
@@ -1489,6 +1689,14 @@ impl<T: Config> Pallet<T> {
 					},
 				)?;
 
+				for pair in [Pairs::Base, Pairs::Quote] {
+					Self::record_pool_flow(
+						asset_pair,
+						pair,
+						IncreaseOrDecrease::Decrease(assets_withdrawn[pair]),
+					);
+				}
+
 				(
 					IncreaseOrDecrease::Decrease(burnt_liquidity),
 					position_info,
@@ -1508,6 +1716,13 @@ impl<T: Config> Pallet<T> {
 					},
 				)
 			})?;
+		for pair in [Pairs::Base, Pairs::Quote] {
+			Self::record_pool_flow(
+				asset_pair,
+				pair,
+				IncreaseOrDecrease::Decrease(collected_fees[pair]),
+			);
+		}
 
 		if position_info.liquidity == 0 {
 			if let Some(range_orders) = pool.range_orders_cache.get_mut(lp) {
@@ -1613,6 +1828,129 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// The total amount of each asset that remains available to be bought from the pool, across all
+	/// limit and range orders.
+	fn pool_reserves(
+		pool_state: &mut PoolState<(T::AccountId, OrderId)>,
+	) -> Result<PoolPairsMap<Amount>, Error<T>> {
+		let limit_orders = pool_state
+			.limit_order_depth(MIN_TICK..MAX_TICK)
+			.map_err(|_| Error::<T>::InvalidTickRange)?;
+		let range_orders = pool_state
+			.range_order_depth(MIN_TICK..MAX_TICK)
+			.map_err(|_| Error::<T>::InvalidTickRange)?;
+		Ok(PoolPairsMap {
+			base: limit_orders.base.1.saturating_add(range_orders.base.1),
+			quote: limit_orders.quote.1.saturating_add(range_orders.quote.1),
+		})
+	}
+
+	/// Records assets paid into (`Increase`) or out of (`Decrease`) a pool in its ledger.
+	fn record_pool_flow(
+		asset_pair: &AssetPair,
+		pair: Pairs,
+		flow: IncreaseOrDecrease<AssetAmount>,
+	) {
+		if flow.abs().is_zero() {
+			return
+		}
+		PoolLedger::<T>::mutate(asset_pair, |ledger| match flow {
+			IncreaseOrDecrease::Increase(amount) => ledger[pair].saturating_accrue(amount),
+			IncreaseOrDecrease::Decrease(amount) => ledger[pair].saturating_reduce(amount),
+		});
+	}
+
+	/// The amount of each asset the pool owes to its liquidity providers: the assets held by every
+	/// open order, plus any fees and bought amounts that haven't been collected yet. Also returns
+	/// the number of open orders.
+	pub(crate) fn pool_liabilities(pool: &Pool<T>) -> (PoolPairsMap<Amount>, u32) {
+		let mut liabilities = PoolPairsMap::<Amount>::default();
+		let mut order_count = 0u32;
+		for side in [Side::Sell, Side::Buy] {
+			for (_, _, collected, position_info) in pool.pool_state.limit_orders(side) {
+				let (sold, bought) = (side.to_sold_pair(), !side.to_sold_pair());
+				liabilities[sold] = liabilities[sold].saturating_add(position_info.amount);
+				liabilities[bought] = liabilities[bought]
+					.saturating_add(collected.fees)
+					.saturating_add(collected.bought_amount);
+				order_count.saturating_accrue(1);
+			}
+		}
+		for (_, range, collected, position_info) in pool.pool_state.range_orders() {
+			let amounts = pool
+				.pool_state
+				.range_order_liquidity_value(range, position_info.liquidity)
+				.unwrap_or_default();
+			for pair in [Pairs::Base, Pairs::Quote] {
+				liabilities[pair] = liabilities[pair]
+					.saturating_add(amounts[pair])
+					.saturating_add(collected.fees[pair]);
+			}
+			order_count.saturating_accrue(1);
+		}
+		(liabilities, order_count)
+	}
+
+	/// The pool after the one checked last, wrapping around to the first pool.
+	fn next_pool_to_check() -> Option<(AssetPair, Pool<T>)> {
+		LastCheckedPool::<T>::get()
+			.and_then(|asset_pair| {
+				Pools::<T>::iter_from(Pools::<T>::hashed_key_for(asset_pair)).next()
+			})
+			.or_else(|| Pools::<T>::iter().next())
+	}
+
+	/// Checks that the pool's liabilities match the net flows recorded in its ledger, to within
+	/// [POOL_INVARIANT_TOLERANCE]. Also returns the number of open orders that were checked.
+	pub(crate) fn pool_matches_ledger(asset_pair: &AssetPair, pool: &Pool<T>) -> (bool, u32) {
+		let ledger = PoolLedger::<T>::get(asset_pair);
+		let (liabilities, order_count) = Self::pool_liabilities(pool);
+		let matches_ledger = [Pairs::Base, Pairs::Quote].into_iter().all(|pair| {
+			AssetAmount::try_from(liabilities[pair]).is_ok_and(|liability| {
+				liability.abs_diff(ledger[pair]) <=
+					(POOL_INVARIANT_TOLERANCE * ledger[pair]).saturating_add(order_count.into())
+			})
+		});
+		(matches_ledger, order_count)
+	}
+
+	/// Returns the first block of the bucket containing `block`, and the history slot it maps to.
+	fn swap_volume_bucket(block: BlockNumberFor<T>) -> (BlockNumberFor<T>, u32) {
+		let bucket_size = T::SwapVolumeBucketSize::get().max(1u32.into());
+		let history_length = T::MaxSwapVolumeHistory::get().max(1);
+		let slot = (block / bucket_size) % history_length.into();
+		(block - block % bucket_size, slot.unique_saturated_into())
+	}
+
+	fn record_swap_volume(asset: any::Asset, amount: AssetAmount) {
+		if amount.is_zero() {
+			return
+		}
+		SwapVolume::<T>::mutate(asset, |volume| volume.saturating_accrue(amount));
+		let (bucket_start, slot) =
+			Self::swap_volume_bucket(frame_system::Pallet::<T>::block_number());
+		SwapVolumeHistory::<T>::mutate(asset, slot, |entry| match entry {
+			Some((start, volume)) if *start == bucket_start => volume.saturating_accrue(amount),
+			_ => *entry = Some((bucket_start, amount)),
+		});
+	}
+
+	/// The swap volume of `asset` in each bucket of the current history window, oldest first.
+	/// Buckets without any swaps are omitted.
+	pub fn swap_volume_history(asset: any::Asset) -> Vec<(BlockNumberFor<T>, AssetAmount)> {
+		let (current_bucket_start, _) =
+			Self::swap_volume_bucket(frame_system::Pallet::<T>::block_number());
+		let window = T::SwapVolumeBucketSize::get()
+			.max(1u32.into())
+			.saturating_mul(T::MaxSwapVolumeHistory::get().saturating_sub(1).into());
+		let oldest_bucket_start = current_bucket_start.saturating_sub(window);
+		let mut history = SwapVolumeHistory::<T>::iter_prefix_values(asset)
+			.filter(|(start, _)| *start >= oldest_bucket_start)
+			.collect::<Vec<_>>();
+		history.sort_unstable();
+		history
+	}
+
 	fn try_mutate_pool<
 		R,
 		E: From<pallet::Error<T>>,
@@ -1657,6 +1995,35 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Returns a summary of every pool that holds any liquidity.
+	pub fn all_pools() -> Vec<PoolSummary> {
+		Pools::<T>::iter()
+			.filter_map(|(asset_pair, mut pool)| {
+				let reserves = Self::pool_reserves(&mut pool.pool_state).ok()?;
+				if reserves.base.is_zero() && reserves.quote.is_zero() {
+					return None
+				}
+				let PoolPairsMap { base: base_asset, quote: quote_asset } = asset_pair.assets();
+				Some(PoolSummary {
+					base_asset,
+					quote_asset,
+					reserves,
+					price: PoolPriceV2 {
+						sell: pool
+							.pool_state
+							.current_price(Side::Sell)
+							.map(|(_, sqrt_price, _)| sqrt_price),
+						buy: pool
+							.pool_state
+							.current_price(Side::Buy)
+							.map(|(_, sqrt_price, _)| sqrt_price),
+						range_order: pool.pool_state.current_range_order_pool_price(),
+					},
+				})
+			})
+			.collect()
+	}
+
 	pub fn required_asset_ratio_for_range_order(
 		base_asset: any::Asset,
 		quote_asset: any::Asset,
@@ -1987,6 +2354,11 @@ impl<T: Config> Pallet<T> {
 			asset_pair.assets()[!order.to_sold_pair()],
 			bought_amount,
 		)?;
+		Self::record_pool_flow(
+			asset_pair,
+			!order.to_sold_pair(),
+			IncreaseOrDecrease::Decrease(collected_fees.saturating_add(bought_amount)),
+		);
 
 		let limit_orders = &mut pool.limit_orders_cache[order.to_sold_pair()];
 		if position_info.amount.is_zero() {
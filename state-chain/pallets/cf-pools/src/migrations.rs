@@ -1,4 +1,9 @@
 use crate::Pallet;
-use cf_runtime_upgrade_utilities::PlaceholderMigration;
+use cf_runtime_upgrade_utilities::{PlaceholderMigration, VersionedMigration};
 
-pub type PalletMigration<T> = PlaceholderMigration<Pallet<T>, 4>;
+pub mod initialize_pool_ledger;
+
+pub type PalletMigration<T> = (
+	VersionedMigration<Pallet<T>, initialize_pool_ledger::Migration<T>, 4, 5>,
+	PlaceholderMigration<Pallet<T>, 5>,
+);
@@ -0,0 +1,73 @@
+use crate::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+
+pub struct Migration<T: Config>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// Existing pools have no recorded flows, so their ledger starts from what they currently
+		// owe to their liquidity providers.
+		for (asset_pair, pool) in Pools::<T>::iter() {
+			let (liabilities, _) = Pallet::<T>::pool_liabilities(&pool);
+			PoolLedger::<T>::insert(
+				asset_pair,
+				liabilities.map(|amount| AssetAmount::try_from(amount).unwrap_or(AssetAmount::MAX)),
+			);
+		}
+
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod migration_tests {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_migration() {
+		new_test_ext().execute_with(|| {
+			let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+			assert_ok!(LiquidityPools::new_pool(
+				RuntimeOrigin::root(),
+				Asset::Eth,
+				STABLE_ASSET,
+				Default::default(),
+				cf_amm::common::price_at_tick(0).unwrap(),
+			));
+			assert_ok!(LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				STABLE_ASSET,
+				Side::Sell,
+				0,
+				Some(0),
+				1_000,
+			));
+			PoolLedger::<Test>::remove(asset_pair);
+
+			super::Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(PoolLedger::<Test>::get(asset_pair), PoolPairsMap { base: 1_000, quote: 0 });
+			assert_eq!(
+				Pallet::<Test>::pool_matches_ledger(
+					&asset_pair,
+					&Pools::<Test>::get(asset_pair).unwrap()
+				),
+				(true, 1)
+			);
+		});
+	}
+}
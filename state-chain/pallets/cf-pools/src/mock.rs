@@ -66,6 +66,10 @@ impl_mock_chainflip!(Test);
 parameter_types! {
 	// 20 Basis Points
 	pub static NetworkFee: Permill = Permill::from_perthousand(2);
+	pub const MinFlipBuyInterval: u64 = 2;
+	pub const MaxFlipBuyInterval: u64 = 1_000;
+	pub const SwapVolumeBucketSize: u64 = 2;
+	pub const MaxSwapVolumeHistory: u32 = 3;
 	pub static AliceCollectedEth: AssetAmount = Default::default();
 	pub static AliceCollectedUsdc: AssetAmount = Default::default();
 	pub static BobCollectedEth: AssetAmount = Default::default();
@@ -150,6 +154,10 @@ impl pallet_cf_pools::Config for Test {
 	type LpBalance = MockBalance;
 	type SwapQueueApi = MockSwapQueueApi;
 	type NetworkFee = NetworkFee;
+	type MinFlipBuyInterval = MinFlipBuyInterval;
+	type MaxFlipBuyInterval = MaxFlipBuyInterval;
+	type SwapVolumeBucketSize = SwapVolumeBucketSize;
+	type MaxSwapVolumeHistory = MaxSwapVolumeHistory;
 	type SafeMode = MockRuntimeSafeMode;
 	type WeightInfo = ();
 }
@@ -1,9 +1,13 @@
 use crate::{
 	self as pallet_cf_pools, mock::*, utilities, AskBidMap, AssetAmounts, AssetPair,
-	CollectedNetworkFee, Error, Event, FlipBuyInterval, LimitOrder, PoolInfo, PoolOrders,
-	PoolPairsMap, Pools, RangeOrder, RangeOrderSize, ScheduledLimitOrderUpdates, STABLE_ASSET,
+	CollectedNetworkFee, Error, Event, FlipBuyInterval, LastCheckedPool, LastFlipBuyBlock,
+	LimitOrder, MinimumPoolReserve, PoolInfo, PoolOrders, PoolPairsMap, PoolSummary, Pools,
+	RangeOrder, RangeOrderSize, ScheduledLimitOrderUpdates, SwapVolume, SwapVolumeHistory,
+	POOL_INVARIANT_CHECK_INTERVAL, STABLE_ASSET,
+};
+use cf_amm::common::{
+	price_at_tick, tick_at_price, Price, Side, Tick, MAX_TICK, MIN_TICK, PRICE_FRACTIONAL_BITS,
 };
-use cf_amm::common::{price_at_tick, tick_at_price, Price, Side, Tick, PRICE_FRACTIONAL_BITS};
 use cf_chains::Ethereum;
 use cf_primitives::{chains::assets::any::Asset, AssetAmount, SwapOutput};
 use cf_test_utilities::{assert_events_match, assert_has_event, last_event};
@@ -237,10 +241,16 @@ fn test_buy_back_flip() {
 		// Nothing is bought if we're not at the interval.
 		LiquidityPools::on_initialize(INTERVAL * 3 - 1);
 		assert_eq!(EXPECTED_COLLECTED_FEES, CollectedNetworkFee::<Test>::get());
+		assert_eq!(LastFlipBuyBlock::<Test>::get(), None);
 
 		// If we're at an interval, we should buy flip.
 		LiquidityPools::on_initialize(INTERVAL * 3);
 		assert_eq!(0, CollectedNetworkFee::<Test>::get());
+		assert_eq!(LastFlipBuyBlock::<Test>::get(), Some(INTERVAL * 3));
+
+		// Nothing is bought at the next interval if no fees have been collected since.
+		LiquidityPools::on_initialize(INTERVAL * 4);
+		assert_eq!(LastFlipBuyBlock::<Test>::get(), Some(INTERVAL * 3));
 		assert_eq!(
 			MockSwapQueueApi::get_swap_queue()
 				.first()
@@ -1348,3 +1358,323 @@ fn can_accept_additional_limit_orders() {
 		)
 	});
 }
+
+#[test]
+fn swap_volume_is_recorded_per_asset() {
+	new_test_ext().execute_with(|| {
+		for asset in [Asset::Eth, Asset::Flip] {
+			assert_ok!(LiquidityPools::new_pool(
+				RuntimeOrigin::root(),
+				asset,
+				STABLE_ASSET,
+				Default::default(),
+				price_at_tick(0).unwrap(),
+			));
+			for side in [Side::Buy, Side::Sell] {
+				assert_ok!(LiquidityPools::set_limit_order(
+					RuntimeOrigin::signed(ALICE),
+					asset,
+					STABLE_ASSET,
+					side,
+					0,
+					Some(0),
+					1_000_000_000,
+				));
+			}
+		}
+
+		System::set_block_number(1);
+		LiquidityPools::swap_with_network_fee(Asset::Eth, STABLE_ASSET, 1_000).unwrap();
+		LiquidityPools::swap_with_network_fee(STABLE_ASSET, Asset::Flip, 2_000).unwrap();
+		assert_eq!(SwapVolume::<Test>::get(Asset::Eth), 1_000);
+		assert_eq!(SwapVolume::<Test>::get(STABLE_ASSET), 1_996);
+		assert_eq!(SwapVolume::<Test>::get(Asset::Flip), 0);
+
+		// Both legs of a two-hop swap count towards the volume of their input asset.
+		System::set_block_number(2);
+		let SwapOutput { intermediary, .. } =
+			LiquidityPools::swap_with_network_fee(Asset::Flip, Asset::Eth, 3_000).unwrap();
+		assert_eq!(SwapVolume::<Test>::get(Asset::Flip), 3_000);
+		assert_eq!(SwapVolume::<Test>::get(STABLE_ASSET), 1_996 + intermediary.unwrap());
+		assert_eq!(SwapVolume::<Test>::get(Asset::Eth), 1_000);
+
+		// Swaps are aggregated into buckets of two blocks, and only the latest buckets are kept.
+		for block in 3..=8 {
+			System::set_block_number(block);
+			LiquidityPools::swap_with_network_fee(Asset::Eth, STABLE_ASSET, 100).unwrap();
+			LiquidityPools::swap_with_network_fee(Asset::Eth, STABLE_ASSET, 100).unwrap();
+		}
+		assert_eq!(SwapVolume::<Test>::get(Asset::Eth), 2_200);
+		assert_eq!(
+			LiquidityPools::swap_volume_history(Asset::Eth),
+			vec![(4, 400), (6, 400), (8, 200)]
+		);
+		assert_eq!(
+			SwapVolumeHistory::<Test>::iter_prefix(Asset::Eth).count(),
+			MaxSwapVolumeHistory::get() as usize
+		);
+
+		// Buckets that have fallen out of the window are not reported.
+		System::set_block_number(12);
+		assert_eq!(LiquidityPools::swap_volume_history(Asset::Eth), vec![(8, 200)]);
+	});
+}
+
+#[test]
+fn swaps_cannot_drain_pool_below_minimum_reserve() {
+	new_test_ext().execute_with(|| {
+		const POOL_SIZE: AssetAmount = 1_000_000;
+		const MINIMUM_RESERVE: AssetAmount = 100_000;
+
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		assert_ok!(LiquidityPools::set_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			0,
+			Some(0),
+			POOL_SIZE,
+		));
+
+		let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+		let minimum_reserve = PoolPairsMap { base: MINIMUM_RESERVE, quote: 0 };
+		assert_ok!(LiquidityPools::set_minimum_pool_reserve(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			minimum_reserve,
+		));
+		assert_eq!(MinimumPoolReserve::<Test>::get(asset_pair), minimum_reserve);
+		assert_has_event::<Test>(RuntimeEvent::LiquidityPools(Event::MinimumPoolReserveSet {
+			asset_pair,
+			minimum_reserve,
+		}));
+
+		// Buying all of the Eth in the pool is not allowed.
+		assert_noop!(
+			LiquidityPools::swap_single_leg(STABLE_ASSET, Asset::Eth, POOL_SIZE),
+			Error::<Test>::InsufficientPoolLiquidity,
+		);
+
+		// Buying up to the minimum reserve is allowed.
+		assert_eq!(
+			LiquidityPools::swap_single_leg(STABLE_ASSET, Asset::Eth, POOL_SIZE - MINIMUM_RESERVE),
+			Ok(POOL_SIZE - MINIMUM_RESERVE)
+		);
+		assert_noop!(
+			LiquidityPools::swap_single_leg(STABLE_ASSET, Asset::Eth, 1),
+			Error::<Test>::InsufficientPoolLiquidity,
+		);
+		assert_eq!(
+			LiquidityPools::pool_depth(Asset::Eth, STABLE_ASSET, MIN_TICK..MAX_TICK)
+				.unwrap()
+				.asks
+				.limit_orders
+				.depth,
+			MINIMUM_RESERVE.into()
+		);
+
+		// Disabling the minimum allows the pool to be drained.
+		assert_ok!(LiquidityPools::set_minimum_pool_reserve(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+		));
+		assert_eq!(
+			LiquidityPools::swap_single_leg(STABLE_ASSET, Asset::Eth, MINIMUM_RESERVE),
+			Ok(MINIMUM_RESERVE)
+		);
+	});
+}
+
+#[test]
+fn pool_ledger_divergence_is_reported() {
+	fn assert_no_violation() {
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::LiquidityPools(Event::PoolInvariantViolation { .. })
+		)));
+	}
+
+	new_test_ext().execute_with(|| {
+		let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		assert_ok!(LiquidityPools::set_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			0,
+			Some(0),
+			1_000_000,
+		));
+		assert_ok!(LiquidityPools::set_range_order(
+			RuntimeOrigin::signed(BOB),
+			Asset::Eth,
+			STABLE_ASSET,
+			0,
+			Some(-100..100),
+			RangeOrderSize::Liquidity { liquidity: 1_000_000 },
+		));
+
+		// Swaps and withdrawals are recorded in the ledger, so the pool stays consistent.
+		assert_ok!(LiquidityPools::swap_single_leg(STABLE_ASSET, Asset::Eth, 1_000));
+		assert_ok!(LiquidityPools::set_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			0,
+			Some(0),
+			0,
+		));
+		LiquidityPools::on_initialize(POOL_INVARIANT_CHECK_INTERVAL.into());
+		assert_no_violation();
+
+		// Add liquidity to the pool that was never paid in.
+		Pools::<Test>::mutate(asset_pair, |pool| {
+			pool.as_mut()
+				.unwrap()
+				.pool_state
+				.collect_and_mint_limit_order(&(BOB, 1), Side::Sell, 10, 1_000_000.into())
+				.unwrap();
+		});
+
+		// The check only runs periodically.
+		LiquidityPools::on_initialize((POOL_INVARIANT_CHECK_INTERVAL + 1).into());
+		assert_no_violation();
+
+		LiquidityPools::on_initialize((2 * POOL_INVARIANT_CHECK_INTERVAL).into());
+		assert_has_event::<Test>(RuntimeEvent::LiquidityPools(Event::PoolInvariantViolation {
+			asset_pair,
+		}));
+	});
+}
+
+#[test]
+fn pool_invariant_check_takes_one_pool_at_a_time() {
+	new_test_ext().execute_with(|| {
+		for asset in [Asset::Eth, Asset::Flip] {
+			assert_ok!(LiquidityPools::new_pool(
+				RuntimeOrigin::root(),
+				asset,
+				STABLE_ASSET,
+				Default::default(),
+				price_at_tick(0).unwrap(),
+			));
+		}
+
+		let checked_pools = (1..=3)
+			.map(|n| {
+				LiquidityPools::on_initialize((n * POOL_INVARIANT_CHECK_INTERVAL).into());
+				LastCheckedPool::<Test>::get().unwrap()
+			})
+			.collect::<Vec<_>>();
+
+		// Each pool is checked in turn, then the check starts over.
+		assert_ne!(checked_pools[0], checked_pools[1]);
+		assert_eq!(checked_pools[0], checked_pools[2]);
+	});
+}
+
+#[test]
+fn buy_interval_must_be_within_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LiquidityPools::update_buy_interval(RuntimeOrigin::root(), 0),
+			Error::<Test>::ZeroBuyIntervalNotAllowed,
+		);
+		assert_noop!(
+			LiquidityPools::update_buy_interval(
+				RuntimeOrigin::root(),
+				MinFlipBuyInterval::get() - 1
+			),
+			Error::<Test>::BuyIntervalTooShort,
+		);
+		assert_noop!(
+			LiquidityPools::update_buy_interval(
+				RuntimeOrigin::root(),
+				MaxFlipBuyInterval::get() + 1
+			),
+			Error::<Test>::BuyIntervalTooLong,
+		);
+
+		for buy_interval in [MinFlipBuyInterval::get(), MaxFlipBuyInterval::get()] {
+			assert_ok!(LiquidityPools::update_buy_interval(RuntimeOrigin::root(), buy_interval));
+			assert_eq!(FlipBuyInterval::<Test>::get(), buy_interval);
+			System::assert_last_event(RuntimeEvent::LiquidityPools(
+				Event::<Test>::UpdatedBuyInterval { buy_interval },
+			));
+		}
+	});
+}
+
+#[test]
+fn can_get_all_pools() {
+	new_test_ext().execute_with(|| {
+		const LIQUIDITY: AssetAmount = 1_000_000;
+		let assets = [Asset::Eth, Asset::Flip, Asset::Usdt];
+
+		for asset in assets.into_iter().chain([Asset::Dot]) {
+			assert_ok!(LiquidityPools::new_pool(
+				RuntimeOrigin::root(),
+				asset,
+				STABLE_ASSET,
+				Default::default(),
+				price_at_tick(0).unwrap(),
+			));
+		}
+		// The Dot pool is left without any liquidity.
+		for (i, asset) in assets.into_iter().enumerate() {
+			assert_ok!(LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				asset,
+				STABLE_ASSET,
+				Side::Sell,
+				0,
+				Some(0),
+				LIQUIDITY * (i as u128 + 1),
+			));
+			assert_ok!(LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				asset,
+				STABLE_ASSET,
+				Side::Buy,
+				1,
+				Some(0),
+				LIQUIDITY,
+			));
+		}
+
+		let all_pools = LiquidityPools::all_pools();
+		assert_eq!(all_pools.len(), assets.len());
+		for (i, asset) in assets.into_iter().enumerate() {
+			assert_eq!(
+				all_pools.iter().find(|pool| pool.base_asset == asset),
+				Some(&PoolSummary {
+					base_asset: asset,
+					quote_asset: STABLE_ASSET,
+					reserves: PoolPairsMap {
+						base: (LIQUIDITY * (i as u128 + 1)).into(),
+						quote: LIQUIDITY.into()
+					},
+					price: LiquidityPools::pool_price(asset, STABLE_ASSET).unwrap(),
+				})
+			);
+		}
+	});
+}
@@ -41,6 +41,8 @@ pub trait WeightInfo {
 	fn set_pool_fees() -> Weight;
 	fn schedule_limit_order_update() -> Weight;
 	fn set_maximum_price_impact(n: u32, ) -> Weight;
+	fn set_minimum_pool_reserve() -> Weight;
+	fn check_pool_invariant(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_cf_pools using the Substrate node and recommended hardware.
@@ -193,6 +195,22 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LiquidityPools::MinimumPoolReserve` (r:0 w:1)
+	/// Proof: `LiquidityPools::MinimumPoolReserve` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_minimum_pool_reserve() -> Weight {
+		// Placeholder until the weights are regenerated from the `set_minimum_pool_reserve` benchmark.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LiquidityPools::PoolLedger` (r:1 w:0)
+	/// Proof: `LiquidityPools::PoolLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 100]`.
+	fn check_pool_invariant(n: u32, ) -> Weight {
+		// Placeholder until the weights are regenerated from the `check_pool_invariant` benchmark.
+		Weight::from_parts(10_000_000, 3497)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -344,4 +362,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LiquidityPools::MinimumPoolReserve` (r:0 w:1)
+	/// Proof: `LiquidityPools::MinimumPoolReserve` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_minimum_pool_reserve() -> Weight {
+		// Placeholder until the weights are regenerated from the `set_minimum_pool_reserve` benchmark.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LiquidityPools::PoolLedger` (r:1 w:0)
+	/// Proof: `LiquidityPools::PoolLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 100]`.
+	fn check_pool_invariant(n: u32, ) -> Weight {
+		// Placeholder until the weights are regenerated from the `check_pool_invariant` benchmark.
+		Weight::from_parts(10_000_000, 3497)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
 }
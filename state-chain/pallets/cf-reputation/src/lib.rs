@@ -184,6 +184,19 @@ pub mod pallet {
 	/// reported via the [ChainflipOffenceReportingAdapter].
 	pub type OffenceTimeSlotTracker<T: Config> = StorageMap<_, Identity, ReportId, OpaqueTimeSlot>;
 
+	/// The number of times each validator has been reported for each offence.
+	#[pallet::storage]
+	#[pallet::getter(fn offence_count)]
+	pub type OffenceCounts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ValidatorId,
+		Twox64Concat,
+		T::Offence,
+		u32,
+		ValueQuery,
+	>;
+
 	/// The last block numbers at which validators submitted a heartbeat.
 	#[pallet::storage]
 	#[pallet::getter(fn last_heartbeat)]
@@ -400,6 +413,12 @@ impl<T: Config> OffenceReporter for Pallet<T> {
 		let offence = offence.into();
 		let penalty = Self::resolve_penalty_for(offence);
 
+		validators.clone().into_iter().for_each(|validator_id| {
+			OffenceCounts::<T>::mutate(&validator_id, offence, |count| {
+				*count = count.saturating_add(1);
+			});
+		});
+
 		if penalty.reputation > 0 {
 			validators.clone().into_iter().for_each(|validator_id| {
 				Reputations::<T>::mutate(&validator_id, |rep| {
@@ -520,5 +539,6 @@ impl<T: Config> OnKilledAccount<T::ValidatorId> for Pallet<T> {
 	fn on_killed_account(who: &T::ValidatorId) {
 		Reputations::<T>::remove(who);
 		LastHeartbeat::<T>::remove(who);
+		let _ = OffenceCounts::<T>::clear_prefix(who, u32::MAX, None);
 	}
 }
@@ -205,6 +205,29 @@ fn reporting_any_offence_should_penalise_reputation_points_and_suspend() {
 	});
 }
 
+#[test]
+fn offences_are_counted_per_validator() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ReputationPallet::offence_count(ALICE, AllOffences::ForgettingYourYubiKey), 0);
+
+		<ReputationPallet as OffenceReporter>::report_many(
+			AllOffences::ForgettingYourYubiKey,
+			[ALICE, BOB],
+		);
+		ReputationPallet::report(AllOffences::ForgettingYourYubiKey, ALICE);
+		ReputationPallet::report(AllOffences::NotLockingYourComputer, BOB);
+
+		assert_eq!(ReputationPallet::offence_count(ALICE, AllOffences::ForgettingYourYubiKey), 2);
+		assert_eq!(ReputationPallet::offence_count(ALICE, AllOffences::NotLockingYourComputer), 0);
+		assert_eq!(ReputationPallet::offence_count(BOB, AllOffences::ForgettingYourYubiKey), 1);
+		assert_eq!(ReputationPallet::offence_count(BOB, AllOffences::NotLockingYourComputer), 1);
+
+		<ReputationPallet as OnKilledAccount<_>>::on_killed_account(&ALICE);
+		assert_eq!(ReputationPallet::offence_count(ALICE, AllOffences::ForgettingYourYubiKey), 0);
+		assert_eq!(ReputationPallet::offence_count(BOB, AllOffences::ForgettingYourYubiKey), 1);
+	});
+}
+
 #[test]
 fn suspensions() {
 	new_test_ext().execute_with(|| {
@@ -431,6 +431,24 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Reserve the weight of the swaps that are due to be executed in `on_finalize`, including
+		/// any left unprocessed by earlier blocks.
+		fn on_initialize(current_block: BlockNumberFor<T>) -> Weight {
+			let mut block = FirstUnprocessedBlock::<T>::get();
+			let mut reads = 1u64;
+			let mut swaps = 0usize;
+			while block <= current_block {
+				swaps.saturating_accrue(SwapQueue::<T>::decode_len(block).unwrap_or_default());
+				reads.saturating_accrue(1);
+				block += 1u32.into();
+			}
+			// Each swap is executed in at most two legs.
+			T::DbWeight::get().reads(reads).saturating_add(
+				T::SwappingApi::swap_single_leg_weight()
+					.saturating_mul(swaps.saturating_mul(2) as u64),
+			)
+		}
+
 		/// Execute all swaps in the SwapQueue
 		fn on_finalize(current_block: BlockNumberFor<T>) {
 			if !T::SafeMode::get().swaps_enabled {
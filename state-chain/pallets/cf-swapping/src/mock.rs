@@ -77,6 +77,8 @@ thread_local! {
 	pub static SWAPS_SHOULD_FAIL: Cell<bool> = Cell::new(false);
 }
 
+pub const MOCK_SWAP_LEG_WEIGHT: Weight = Weight::from_parts(1_000, 0);
+
 pub struct MockSwappingApi;
 
 impl MockSwappingApi {
@@ -109,6 +111,10 @@ impl SwappingApi for MockSwappingApi {
 		Swaps::set(swaps);
 		Ok((input_amount as f64 * SwapRate::get()) as AssetAmount)
 	}
+
+	fn swap_single_leg_weight() -> Weight {
+		MOCK_SWAP_LEG_WEIGHT
+	}
 }
 
 pub struct MockWeightInfo;
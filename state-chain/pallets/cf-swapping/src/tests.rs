@@ -1219,6 +1219,20 @@ fn cannot_swap_in_safe_mode() {
 	});
 }
 
+#[test]
+fn swaps_left_unprocessed_by_earlier_blocks_are_weighed() {
+	new_test_ext().execute_with(|| {
+		const CURRENT_BLOCK: u64 = 5;
+		// A failed batch leaves the swaps of the previous block unprocessed.
+		FirstUnprocessedBlock::<Test>::put(CURRENT_BLOCK - 1);
+		SwapQueue::<Test>::insert(CURRENT_BLOCK - 1, generate_test_swaps());
+		SwapQueue::<Test>::insert(CURRENT_BLOCK, generate_test_swaps());
+
+		// Each of the 8 swaps is executed in at most two legs.
+		assert_eq!(Swapping::on_initialize(CURRENT_BLOCK), MOCK_SWAP_LEG_WEIGHT.saturating_mul(16));
+	});
+}
+
 #[test]
 fn cannot_withdraw_in_safe_mode() {
 	new_test_ext().execute_with(|| {
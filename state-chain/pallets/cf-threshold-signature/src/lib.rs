@@ -38,7 +38,9 @@ use frame_support::{
 		traits::{BlockNumberProvider, Saturating},
 		RuntimeDebug,
 	},
-	traits::{DefensiveOption, EnsureOrigin, Get, StorageVersion, UnfilteredDispatchable},
+	traits::{
+		DefensiveOption, EnsureOrigin, Get, OnKilledAccount, StorageVersion, UnfilteredDispatchable,
+	},
 	weights::Weight,
 	RuntimeDebugNoBound,
 };
@@ -69,6 +71,20 @@ impl<I: 'static> SafeMode for PalletSafeMode<I> {
 	const CODE_GREEN: Self = PalletSafeMode { slashing_enabled: true, _phantom: PhantomData };
 }
 
+/// Running tally of how a validator fared in the keygen and key handover ceremonies it was a
+/// candidate for.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Default, Copy, Clone, PartialEq, Eq, RuntimeDebug,
+)]
+pub struct KeygenParticipationStats {
+	/// Ceremonies the validator responded to without being reported as an offender.
+	pub successful_keygens: u32,
+	/// Ceremonies the validator responded to but was reported as an offender.
+	pub failed_keygens: u32,
+	/// Ceremonies the validator did not respond to before the response timeout.
+	pub timeouts: u32,
+}
+
 pub type SignatureFor<T, I> =
 	<<T as Config<I>>::TargetChainCrypto as ChainCrypto>::ThresholdSignature;
 type PayloadFor<T, I> = <<T as Config<I>>::TargetChainCrypto as ChainCrypto>::Payload;
@@ -503,6 +519,12 @@ pub mod pallet {
 	pub(super) type KeyHandoverResolutionPendingSince<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+	/// Per-validator participation stats for keygen and key handover ceremonies.
+	#[pallet::storage]
+	#[pallet::getter(fn participation_stats)]
+	pub type ParticipationStats<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::ValidatorId, KeygenParticipationStats, ValueQuery>;
+
 	#[pallet::storage]
 	pub(super) type KeygenResponseTimeout<T: Config<I>, I: 'static = ()> = StorageValue<
 		_,
@@ -654,8 +676,11 @@ pub mod pallet {
 		KeyHandoverVerificationFailure {
 			handover_ceremony_id: CeremonyId,
 		},
-		/// Keygen has failed \[ceremony_id\]
-		KeygenFailure(CeremonyId),
+		/// Keygen has failed, and the given offenders have been reported.
+		KeygenFailure {
+			ceremony_id: CeremonyId,
+			offenders: BTreeSet<T::ValidatorId>,
+		},
 		/// Keygen response timeout has occurred \[ceremony_id\]
 		KeygenResponseTimeout(CeremonyId),
 		KeyHandoverResponseTimeout {
@@ -733,8 +758,8 @@ pub mod pallet {
 							},
 							|offenders| {
 								Self::terminate_rotation(
-									offenders,
-									Event::KeygenFailure(ceremony_id),
+									offenders.clone(),
+									Event::KeygenFailure { ceremony_id, offenders },
 								);
 							},
 						);
@@ -878,7 +903,9 @@ pub mod pallet {
 				T::Weights::on_initialize_no_keygen(
 					T::EpochInfo::current_authority_count(),
 					num_retries,
-				) + T::Weights::report_offenders(num_offenders as AuthorityCount)
+				) + T::Weights::report_offenders(num_offenders as AuthorityCount) +
+				// Reporting updates the offence count of each offender.
+				T::DbWeight::get().reads_writes(num_offenders as u64, num_offenders as u64)
 		}
 	}
 
@@ -1342,16 +1369,22 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		};
 
 		let candidate_count = response_status.candidate_count();
+		let candidates = response_status.candidates().clone();
+		let unresponsive = response_status.remaining_candidates().clone();
 		let weight = match response_status.resolve_keygen_outcome(final_key_check) {
 			Ok(new_public_key) => {
 				debug_assert_eq!(
 					remaining_candidate_count, 0,
 					"Can't have success unless all candidates responded"
 				);
+				Self::record_participation(&candidates, &unresponsive, &Default::default());
 				on_success_outcome(new_public_key);
 				T::Weights::on_initialize_keygen_success_no_pending_sig_ceremonies()
 			},
 			Err(offenders) => {
+				// Stats are recorded before the offenders are filtered below, so that responsive
+				// offenders count as failed even if there are too many of them to be reported.
+				Self::record_participation(&candidates, &unresponsive, &offenders);
 				let offenders_len = offenders.len();
 				let offenders = if (offenders_len as AuthorityCount) <
 					cf_utilities::failure_threshold_from_share_count(candidate_count)
@@ -1360,14 +1393,41 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				} else {
 					Default::default()
 				};
+				// Reporting updates the offence count of each offender.
+				let reported = offenders.len() as u64;
 				on_failure_outcome(offenders);
 				T::Weights::on_initialize_keygen_failure_no_pending_sig_ceremonies(
 					offenders_len as u32,
 				)
+				.saturating_add(T::DbWeight::get().reads_writes(reported, reported))
 			},
 		};
 		PendingSince::kill();
-		weight
+		weight.saturating_add(
+			T::DbWeight::get().reads_writes(candidate_count as u64, candidate_count as u64),
+		)
+	}
+
+	/// Updates the participation stats of every candidate of a resolved keygen or key handover
+	/// ceremony. Unresponsive candidates are counted as timeouts, responsive offenders as failures
+	/// and everyone else as successful.
+	fn record_participation(
+		candidates: &BTreeSet<T::ValidatorId>,
+		unresponsive: &BTreeSet<T::ValidatorId>,
+		offenders: &BTreeSet<T::ValidatorId>,
+	) {
+		for candidate in candidates {
+			ParticipationStats::<T, I>::mutate(candidate, |stats| {
+				let counter = if unresponsive.contains(candidate) {
+					&mut stats.timeouts
+				} else if offenders.contains(candidate) {
+					&mut stats.failed_keygens
+				} else {
+					&mut stats.successful_keygens
+				};
+				*counter = counter.saturating_add(1);
+			});
+		}
 	}
 
 	// Once we've successfully generated the key, we want to do a signing ceremony to verify that
@@ -1578,6 +1638,12 @@ where
 	}
 }
 
+impl<T: Config<I>, I: 'static> OnKilledAccount<T::ValidatorId> for Pallet<T, I> {
+	fn on_killed_account(who: &T::ValidatorId) {
+		ParticipationStats::<T, I>::remove(who);
+	}
+}
+
 impl<T: Config<I>, I: 'static> KeyProvider<T::TargetChainCrypto> for Pallet<T, I> {
 	fn active_epoch_key() -> Option<EpochKey<<T::TargetChainCrypto as ChainCrypto>::AggKey>> {
 		CurrentKeyEpoch::<T, I>::get().map(|current_key_epoch| {
@@ -4,9 +4,9 @@ use std::collections::{BTreeMap, BTreeSet};
 use crate::{
 	mock::*, AttemptCount, AuthorityCount, CeremonyContext, CeremonyId, CurrentEpochIndex, Error,
 	Event as PalletEvent, KeyHandoverResolutionPendingSince, KeyRotationStatus,
-	KeygenFailureVoters, KeygenOutcomeFor, KeygenResolutionPendingSince, KeygenResponseTimeout,
-	KeygenSuccessVoters, PalletOffence, PendingKeyRotation, RequestContext, RequestId,
-	ThresholdSignatureResponseTimeout,
+	KeygenFailureVoters, KeygenOutcomeFor, KeygenParticipationStats, KeygenResolutionPendingSince,
+	KeygenResponseTimeout, KeygenSuccessVoters, PalletOffence, ParticipationStats,
+	PendingKeyRotation, RequestContext, RequestId, ThresholdSignatureResponseTimeout,
 };
 
 use cf_chains::mocks::{MockAggKey, MockEthereumChainCrypto};
@@ -27,7 +27,7 @@ use frame_support::{
 	assert_err, assert_noop, assert_ok,
 	instances::Instance1,
 	pallet_prelude::DispatchResultWithPostInfo,
-	traits::{Hooks, OnInitialize},
+	traits::{Hooks, OnInitialize, OnKilledAccount},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::traits::BlockNumberProvider;
@@ -922,12 +922,13 @@ fn keygen_failure(
 
 	let ceremony_id = current_ceremony_id();
 
-	EvmThresholdSigner::terminate_rotation(
-		bad_candidates.clone(),
-		PalletEvent::KeygenFailure(ceremony_id),
-	);
+	let event = PalletEvent::KeygenFailure {
+		ceremony_id,
+		offenders: bad_candidates.clone().into_iter().collect(),
+	};
+	EvmThresholdSigner::terminate_rotation(bad_candidates.clone(), event.clone());
 
-	assert_eq!(last_event::<Test>(), PalletEvent::KeygenFailure(ceremony_id).into());
+	assert_eq!(last_event::<Test>(), event.into());
 
 	assert_eq!(
 		EvmThresholdSigner::status(),
@@ -1461,7 +1462,10 @@ fn keygen_report_failure() {
 
 		MockOffenceReporter::assert_reported(PalletOffence::FailedKeygen, vec![CHARLIE]);
 
-		assert_last_events!(crate::Event::KeygenFailure(..));
+		assert_eq!(
+			last_event::<Test>(),
+			PalletEvent::KeygenFailure { ceremony_id, offenders: BTreeSet::from([CHARLIE]) }.into()
+		);
 
 		// Voting has been cleared.
 		assert!(KeygenSuccessVoters::<Test, _>::iter_keys().next().is_none());
@@ -1518,6 +1522,118 @@ fn test_keygen_timeout_period() {
 	});
 }
 
+#[test]
+fn shortened_keygen_response_timeout_resolves_sooner() {
+	const SHORT_TIMEOUT: u64 = 5;
+	assert!(SHORT_TIMEOUT < MOCK_KEYGEN_RESPONSE_TIMEOUT);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmThresholdSigner::set_keygen_response_timeout(
+			RuntimeOrigin::root(),
+			SHORT_TIMEOUT
+		));
+		<EvmThresholdSigner as KeyRotator>::keygen(
+			BTreeSet::from_iter(ALL_CANDIDATES.iter().cloned()),
+			GENESIS_EPOCH,
+		);
+		let ceremony_id = current_ceremony_id();
+		assert_eq!(KeygenResolutionPendingSince::<Test, _>::get(), 1);
+
+		assert_ok!(EvmThresholdSigner::report_keygen_outcome(
+			RuntimeOrigin::signed(ALICE),
+			ceremony_id,
+			Err(BTreeSet::from_iter([CHARLIE]))
+		));
+
+		<EvmThresholdSigner as Hooks<BlockNumberFor<Test>>>::on_initialize(SHORT_TIMEOUT);
+		assert!(KeygenResolutionPendingSince::<Test, _>::exists());
+
+		// The new timeout applies, well before the default one would have elapsed.
+		<EvmThresholdSigner as Hooks<BlockNumberFor<Test>>>::on_initialize(SHORT_TIMEOUT + 1);
+		assert!(!KeygenResolutionPendingSince::<Test, _>::exists());
+		MockOffenceReporter::assert_reported(PalletOffence::FailedKeygen, vec![]);
+	});
+}
+
+#[test]
+fn keygen_timeout_updates_participation_stats() {
+	new_test_ext().execute_with(|| {
+		<EvmThresholdSigner as KeyRotator>::keygen(
+			BTreeSet::from_iter(ALL_CANDIDATES.iter().cloned()),
+			GENESIS_EPOCH,
+		);
+		let ceremony_id = current_ceremony_id();
+
+		for responder in [ALICE, BOB] {
+			assert_ok!(EvmThresholdSigner::report_keygen_outcome(
+				RuntimeOrigin::signed(responder),
+				ceremony_id,
+				Ok(NEW_AGG_PUB_KEY_PRE_HANDOVER)
+			));
+		}
+
+		<EvmThresholdSigner as Hooks<BlockNumberFor<Test>>>::on_initialize(
+			MOCK_KEYGEN_RESPONSE_TIMEOUT + 1,
+		);
+		MockOffenceReporter::assert_reported(PalletOffence::FailedKeygen, vec![CHARLIE]);
+
+		for responder in [ALICE, BOB] {
+			assert_eq!(
+				ParticipationStats::<Test, _>::get(responder),
+				KeygenParticipationStats { successful_keygens: 1, failed_keygens: 0, timeouts: 0 }
+			);
+		}
+		assert_eq!(
+			ParticipationStats::<Test, _>::get(CHARLIE),
+			KeygenParticipationStats { successful_keygens: 0, failed_keygens: 0, timeouts: 1 }
+		);
+	});
+}
+
+#[test]
+fn responsive_offenders_count_as_failed_even_if_too_many_to_report() {
+	new_test_ext().execute_with(|| {
+		<EvmThresholdSigner as KeyRotator>::keygen(
+			BTreeSet::from_iter(ALL_CANDIDATES.iter().cloned()),
+			GENESIS_EPOCH,
+		);
+		let ceremony_id = current_ceremony_id();
+
+		// Every candidate blames the other two, so all of them are offenders.
+		for reporter in ALL_CANDIDATES {
+			assert_ok!(EvmThresholdSigner::report_keygen_outcome(
+				RuntimeOrigin::signed(*reporter),
+				ceremony_id,
+				Err(ALL_CANDIDATES.iter().filter(|id| *id != reporter).cloned().collect())
+			));
+		}
+
+		<EvmThresholdSigner as Hooks<BlockNumberFor<Test>>>::on_initialize(1);
+		MockOffenceReporter::assert_reported(PalletOffence::FailedKeygen, vec![]);
+
+		for candidate in ALL_CANDIDATES {
+			assert_eq!(
+				ParticipationStats::<Test, _>::get(candidate),
+				KeygenParticipationStats { successful_keygens: 0, failed_keygens: 1, timeouts: 0 }
+			);
+		}
+	});
+}
+
+#[test]
+fn participation_stats_are_removed_when_the_account_is_killed() {
+	new_test_ext().execute_with(|| {
+		ParticipationStats::<Test, _>::insert(
+			ALICE,
+			KeygenParticipationStats { successful_keygens: 1, failed_keygens: 2, timeouts: 3 },
+		);
+
+		<EvmThresholdSigner as OnKilledAccount<_>>::on_killed_account(&ALICE);
+
+		assert!(!ParticipationStats::<Test, _>::contains_key(ALICE));
+	});
+}
+
 #[test]
 fn test_key_handover_timeout_period() {
 	new_test_ext().execute_with(|| {
@@ -383,5 +383,19 @@ mod benchmarks {
 
 		assert!(Pallet::<T>::is_bidding(&caller));
 	}
+
+	#[benchmark]
+	fn force_abort_rotation() {
+		try_start_keygen::<T>(3, 50, 1);
+		let call = Call::<T>::force_abort_rotation {};
+		let o = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(o));
+		}
+
+		assert_eq!(CurrentRotationPhase::<T>::get(), RotationPhase::Idle);
+	}
 	// NOTE: Test suite not included due to missing Funding and Reputation pallet in `mock::Test`.
 }
@@ -329,6 +329,8 @@ pub mod pallet {
 		StoppedBidding { account_id: T::AccountId },
 		/// A previously non-bidding account has started bidding.
 		StartedBidding { account_id: T::AccountId },
+		/// Governance has forced the start of a rotation.
+		ForceRotationRequested,
 	}
 
 	#[pallet::error]
@@ -369,6 +371,8 @@ pub mod pallet {
 		AlreadyBidding,
 		/// We are in the auction phase
 		AuctionPhase,
+		/// There is no rotation in a phase that can be aborted.
+		NoAbortableRotation,
 	}
 
 	/// Pallet implements [`Hooks`] trait
@@ -578,6 +582,7 @@ pub mod pallet {
 				Error::<T>::RotationInProgress
 			);
 			ensure!(T::SafeMode::get().authority_rotation_enabled, Error::<T>::RotationsDisabled,);
+			Self::deposit_event(Event::ForceRotationRequested);
 			Self::start_authority_rotation();
 
 			Ok(().into())
@@ -826,6 +831,38 @@ pub mod pallet {
 			Self::deposit_event(Event::StoppedBidding { account_id });
 			Ok(().into())
 		}
+
+		/// [GOVERNANCE] Abort the rotation that is currently in progress.
+		///
+		/// A rotation can only be aborted while keygen or key handover is in progress: once new
+		/// keys are being activated, the rotation has to run to completion.
+		///
+		/// The dispatch origin of this function must be governance.
+		///
+		/// ## Events
+		///
+		/// - [RotationAborted](Event::RotationAborted)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		/// - [NoAbortableRotation](Error::NoAbortableRotation)
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::ValidatorWeightInfo::force_abort_rotation())]
+		pub fn force_abort_rotation(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			ensure!(
+				matches!(
+					CurrentRotationPhase::<T>::get(),
+					RotationPhase::KeygensInProgress(..) |
+						RotationPhase::KeyHandoversInProgress(..)
+				),
+				Error::<T>::NoAbortableRotation
+			);
+			Self::abort_rotation();
+
+			Ok(().into())
+		}
 	}
 
 	#[pallet::genesis_config]
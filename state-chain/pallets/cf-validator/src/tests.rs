@@ -171,6 +171,7 @@ fn should_rotate_when_forced() {
 	new_test_ext().then_execute_with_checks(|| {
 		set_default_test_bids();
 		assert_ok!(ValidatorPallet::force_rotation(RuntimeOrigin::root()));
+		System::assert_has_event(RuntimeEvent::ValidatorPallet(Event::ForceRotationRequested));
 		assert_rotation_phase_matches!(RotationPhase::KeygensInProgress(..));
 		assert_noop!(
 			ValidatorPallet::force_rotation(RuntimeOrigin::root()),
@@ -179,6 +180,57 @@ fn should_rotate_when_forced() {
 	});
 }
 
+#[test]
+fn governance_can_abort_rotation_during_keygen() {
+	new_test_ext().then_execute_with_checks(|| {
+		set_default_test_bids();
+		ValidatorPallet::start_authority_rotation();
+		assert_rotation_phase_matches!(RotationPhase::KeygensInProgress(..));
+
+		System::reset_events();
+		assert_ok!(ValidatorPallet::force_abort_rotation(RuntimeOrigin::root()));
+		assert_rotation_aborted();
+	});
+}
+
+#[test]
+fn governance_can_abort_rotation_during_key_handover() {
+	new_test_ext().then_execute_with_checks(|| {
+		set_default_test_bids();
+		ValidatorPallet::start_authority_rotation();
+		MockKeyRotatorA::keygen_success();
+		ValidatorPallet::on_initialize(1);
+		assert_rotation_phase_matches!(RotationPhase::KeyHandoversInProgress(..));
+
+		System::reset_events();
+		assert_ok!(ValidatorPallet::force_abort_rotation(RuntimeOrigin::root()));
+		assert_rotation_aborted();
+	});
+}
+
+#[test]
+fn cannot_abort_rotation_if_none_is_abortable() {
+	new_test_ext().then_execute_with_checks(|| {
+		assert_noop!(
+			ValidatorPallet::force_abort_rotation(RuntimeOrigin::root()),
+			Error::<Test>::NoAbortableRotation
+		);
+
+		set_default_test_bids();
+		ValidatorPallet::start_authority_rotation();
+		MockKeyRotatorA::keygen_success();
+		ValidatorPallet::on_initialize(1);
+		MockKeyRotatorA::key_handover_success();
+		ValidatorPallet::on_initialize(1);
+		assert_rotation_phase_matches!(RotationPhase::ActivatingKeys(..));
+
+		assert_noop!(
+			ValidatorPallet::force_abort_rotation(RuntimeOrigin::root()),
+			Error::<Test>::NoAbortableRotation
+		);
+	});
+}
+
 #[test]
 fn auction_winners_should_be_the_new_authorities_on_new_epoch() {
 	let genesis_set = BTreeSet::from(GENESIS_AUTHORITIES);
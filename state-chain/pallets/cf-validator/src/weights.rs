@@ -46,6 +46,7 @@ pub trait WeightInfo {
 	fn deregister_as_validator() -> Weight;
 	fn start_bidding() -> Weight;
 	fn stop_bidding() -> Weight;
+	fn force_abort_rotation() -> Weight;
 }
 
 /// Weights for pallet_cf_validator using the Substrate node and recommended hardware.
@@ -360,6 +361,9 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 	fn stop_bidding() -> Weight {
 		Weight::from_parts(1_000_000, 0)
 	}
+	fn force_abort_rotation() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
 }
 
 // For backwards compatibility and tests
@@ -673,4 +677,7 @@ impl WeightInfo for () {
 	fn stop_bidding() -> Weight {
 		Weight::from_parts(1_000_000, 0)
 	}
+	fn force_abort_rotation() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
 }
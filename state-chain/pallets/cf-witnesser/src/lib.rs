@@ -24,7 +24,9 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::GetDispatchInfo,
 	ensure,
-	pallet_prelude::{DispatchResultWithPostInfo, Member, RuntimeDebug},
+	pallet_prelude::{
+		BoundedVec, DispatchResult, DispatchResultWithPostInfo, Member, RuntimeDebug,
+	},
 	storage::with_storage_layer,
 	traits::{EnsureOrigin, Get, UnfilteredDispatchable},
 	Hashable,
@@ -120,6 +122,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type LateWitnessGracePeriod: Get<BlockNumberFor<Self>>;
 
+		/// The maximum number of calls that can be witnessed in a single batch.
+		#[pallet::constant]
+		type MaxWitnessBatch: Get<u32>;
+
 		/// Benchmark stuff
 		type WeightInfo: WeightInfo;
 	}
@@ -336,6 +342,8 @@ pub mod pallet {
 		},
 		/// A witnessed call has been dispatched.
 		CallDispatched { call_hash: CallHash },
+		/// A batch of witness votes has been processed.
+		WitnessBatchProcessed { witness: T::AccountId, successful: u32, failed: u32 },
 	}
 
 	#[pallet::error]
@@ -397,83 +405,11 @@ pub mod pallet {
 		, DispatchClass::Operational))]
 		pub fn witness_at_epoch(
 			origin: OriginFor<T>,
-			mut call: Box<<T as Config>::RuntimeCall>,
+			call: Box<<T as Config>::RuntimeCall>,
 			epoch_index: EpochIndex,
 		) -> DispatchResultWithPostInfo {
 			let who = T::AccountRoleRegistry::ensure_validator(origin)?;
-
-			let last_expired_epoch = T::EpochInfo::last_expired_epoch();
-			let current_epoch = T::EpochInfo::epoch_index();
-			// Ensure the epoch has not yet expired
-			ensure!(epoch_index > last_expired_epoch, Error::<T>::EpochExpired);
-
-			// The number of authorities for the epoch
-			// This value is updated alongside ValidatorIndex, so if we have a authority, we have an
-			// authority count.
-			let num_authorities = T::EpochInfo::authority_count_at_epoch(epoch_index)
-				.ok_or(Error::<T>::InvalidEpoch)?;
-
-			let index = T::EpochInfo::authority_index(epoch_index, &who.into())
-				.ok_or(Error::<T>::UnauthorisedWitness)? as usize;
-
-			// Register the vote
-			let (extra_data, call_hash) = Self::split_calldata(&mut call);
-			let num_votes = Votes::<T>::try_mutate::<_, _, _, Error<T>, _>(
-				&epoch_index,
-				&call_hash,
-				|buffer| {
-					// If there is no storage item, create an empty one.
-					let bytes = buffer.get_or_insert_with(|| {
-						BitVec::<u8, Msb0>::repeat(false, num_authorities as usize).into_vec()
-					});
-
-					// Convert to an addressable bit mask
-					let bits = VoteMask::from_slice_mut(bytes);
-
-					let mut vote_count = bits.count_ones();
-
-					// Get a reference to the existing vote.
-					let mut vote =
-						bits.get_mut(index).ok_or(Error::<T>::AuthorityIndexOutOfBounds)?;
-
-					// Return an error if already voted, otherwise set the indexed bit to `true` to
-					// indicate a vote.
-					if *vote {
-						return Err(Error::<T>::DuplicateWitness)
-					}
-
-					vote_count += 1;
-					*vote = true;
-
-					if let Some(extra_data) = extra_data {
-						ExtraCallData::<T>::append(epoch_index, call_hash, extra_data);
-					}
-
-					Ok(vote_count)
-				},
-			)?;
-
-			// Check if threshold is reached and, if so, apply the voted-on Call.
-			// At the epoch boundary, asynchronicity can cause validators to witness events at a
-			// earlier epoch than intended. We need to check that the same event has not already
-			// been witnessed in the past.
-			if num_votes == success_threshold_from_share_count(num_authorities) as usize &&
-				(last_expired_epoch..=current_epoch)
-					.all(|epoch| CallHashExecuted::<T>::get(epoch, call_hash).is_none())
-			{
-				if let Some(mut extra_data) = ExtraCallData::<T>::get(epoch_index, call_hash) {
-					call.combine_and_inject(&mut extra_data)
-				}
-				if T::SafeMode::get().should_dispatch(&call) {
-					Self::dispatch_call(epoch_index, current_epoch, *call, call_hash);
-				} else {
-					WitnessedCallsScheduledForDispatch::<T>::append((
-						epoch_index,
-						*call,
-						call_hash,
-					));
-				}
-			}
+			Self::do_witness_at_epoch(who, call, epoch_index)?;
 			Ok(().into())
 		}
 
@@ -540,6 +476,40 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Casts a witness vote for each of the `calls`, as if each had been submitted using
+		/// [witness_at_epoch](Call::witness_at_epoch).
+		///
+		/// Calls are processed independently: a failing vote (for example, a duplicate witness)
+		/// does not prevent the remaining calls from being witnessed. The number of calls in the
+		/// batch is limited by [MaxWitnessBatch](Config::MaxWitnessBatch), which is enforced when
+		/// the extrinsic is decoded.
+		///
+		/// ## Events
+		///
+		/// - [WitnessBatchProcessed](Event::WitnessBatchProcessed)
+		#[pallet::call_index(4)]
+		#[pallet::weight({
+			let authority_count =
+				T::EpochInfo::authority_count_at_epoch(*epoch_index).unwrap_or(1u32) as u64;
+			(
+				calls.iter().fold(Weight::zero(), |total, call| {
+					total
+						.saturating_add(T::WeightInfo::witness_at_epoch())
+						.saturating_add(call.get_dispatch_info().weight / authority_count)
+				}),
+				DispatchClass::Operational,
+			)
+		})]
+		pub fn witness_batch(
+			origin: OriginFor<T>,
+			calls: BoundedVec<Box<<T as Config>::RuntimeCall>, T::MaxWitnessBatch>,
+			epoch_index: EpochIndex,
+		) -> DispatchResult {
+			let who = T::AccountRoleRegistry::ensure_validator(origin)?;
+			Self::do_witness_batch(who, calls, epoch_index);
+			Ok(())
+		}
 	}
 
 	/// Witness pallet origin
@@ -556,6 +526,101 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Casts a witness vote for each of the `calls` and returns the number of votes that were
+	/// successfully cast.
+	fn do_witness_batch(
+		who: T::AccountId,
+		calls: BoundedVec<Box<<T as Config>::RuntimeCall>, T::MaxWitnessBatch>,
+		epoch_index: EpochIndex,
+	) -> u32 {
+		let total = calls.len() as u32;
+		let successful = calls
+			.into_iter()
+			.map(|call| Self::do_witness_at_epoch(who.clone(), call, epoch_index))
+			.filter(Result::is_ok)
+			.count() as u32;
+
+		Self::deposit_event(Event::<T>::WitnessBatchProcessed {
+			witness: who,
+			successful,
+			failed: total - successful,
+		});
+
+		successful
+	}
+
+	fn do_witness_at_epoch(
+		who: T::AccountId,
+		mut call: Box<<T as Config>::RuntimeCall>,
+		epoch_index: EpochIndex,
+	) -> DispatchResult {
+		let last_expired_epoch = T::EpochInfo::last_expired_epoch();
+		let current_epoch = T::EpochInfo::epoch_index();
+		// Ensure the epoch has not yet expired
+		ensure!(epoch_index > last_expired_epoch, Error::<T>::EpochExpired);
+
+		// The number of authorities for the epoch
+		// This value is updated alongside ValidatorIndex, so if we have a authority, we have an
+		// authority count.
+		let num_authorities =
+			T::EpochInfo::authority_count_at_epoch(epoch_index).ok_or(Error::<T>::InvalidEpoch)?;
+
+		let index = T::EpochInfo::authority_index(epoch_index, &who.into())
+			.ok_or(Error::<T>::UnauthorisedWitness)? as usize;
+
+		// Register the vote
+		let (extra_data, call_hash) = Self::split_calldata(&mut call);
+		let num_votes =
+			Votes::<T>::try_mutate::<_, _, _, Error<T>, _>(&epoch_index, &call_hash, |buffer| {
+				// If there is no storage item, create an empty one.
+				let bytes = buffer.get_or_insert_with(|| {
+					BitVec::<u8, Msb0>::repeat(false, num_authorities as usize).into_vec()
+				});
+
+				// Convert to an addressable bit mask
+				let bits = VoteMask::from_slice_mut(bytes);
+
+				let mut vote_count = bits.count_ones();
+
+				// Get a reference to the existing vote.
+				let mut vote = bits.get_mut(index).ok_or(Error::<T>::AuthorityIndexOutOfBounds)?;
+
+				// Return an error if already voted, otherwise set the indexed bit to `true` to
+				// indicate a vote.
+				if *vote {
+					return Err(Error::<T>::DuplicateWitness)
+				}
+
+				vote_count += 1;
+				*vote = true;
+
+				if let Some(extra_data) = extra_data {
+					ExtraCallData::<T>::append(epoch_index, call_hash, extra_data);
+				}
+
+				Ok(vote_count)
+			})?;
+
+		// Check if threshold is reached and, if so, apply the voted-on Call.
+		// At the epoch boundary, asynchronicity can cause validators to witness events at a
+		// earlier epoch than intended. We need to check that the same event has not already
+		// been witnessed in the past.
+		if num_votes == success_threshold_from_share_count(num_authorities) as usize &&
+			(last_expired_epoch..=current_epoch)
+				.all(|epoch| CallHashExecuted::<T>::get(epoch, call_hash).is_none())
+		{
+			if let Some(mut extra_data) = ExtraCallData::<T>::get(epoch_index, call_hash) {
+				call.combine_and_inject(&mut extra_data)
+			}
+			if T::SafeMode::get().should_dispatch(&call) {
+				Self::dispatch_call(epoch_index, current_epoch, *call, call_hash);
+			} else {
+				WitnessedCallsScheduledForDispatch::<T>::append((epoch_index, *call, call_hash));
+			}
+		}
+		Ok(())
+	}
+
 	fn split_calldata(call: &mut <T as Config>::RuntimeCall) -> (Option<Vec<u8>>, CallHash) {
 		let extra_data = call.extract();
 		// `extract()` modifies the call, so we need to calculate the call hash *after* this.
@@ -63,6 +63,7 @@ impl_mock_runtime_safe_mode! { witnesser: pallet_cf_witness::PalletSafeMode<Mock
 parameter_types! {
 	pub static AllowCall: bool = true;
 	pub const GracePeriod: u64 = 10u64;
+	pub const MaxWitnessBatch: u32 = 2u32;
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
@@ -85,6 +86,7 @@ impl pallet_cf_witness::Config for Test {
 	type Offence = PalletOffence;
 	type OffenceReporter = OffenceReporter;
 	type LateWitnessGracePeriod = GracePeriod;
+	type MaxWitnessBatch = MaxWitnessBatch;
 	type WeightInfo = ();
 }
 
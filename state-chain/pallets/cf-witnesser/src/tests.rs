@@ -6,12 +6,17 @@ use crate::{
 	CallHash, CallHashExecuted, Config, EpochsToCull, Error, ExtraCallData, PalletOffence,
 	PalletSafeMode, VoteMask, Votes, WitnessDeadline, WitnessedCallsScheduledForDispatch,
 };
-use cf_test_utilities::assert_event_sequence;
+use cf_test_utilities::{assert_event_sequence, assert_has_event};
 use cf_traits::{
 	mocks::account_role_registry::MockAccountRoleRegistry, AccountRoleRegistry, EpochInfo,
 	EpochTransitionHandler, SafeMode, SetSafeMode,
 };
-use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, PalletInfoAccess},
+	weights::Weight,
+};
 use sp_std::collections::btree_set::BTreeSet;
 
 #[test]
@@ -676,3 +681,106 @@ fn can_punish_failed_witnesser_in_previous_epochs() {
 			assert_eq!(WitnessDeadline::<Test>::decode_len(target), None);
 		});
 }
+
+#[test]
+fn can_witness_a_batch_of_calls() {
+	new_test_ext().execute_with(|| {
+		let increment =
+			Box::new(RuntimeCall::Dummy(pallet_dummy::Call::<Test>::increment_value {}));
+		let remark =
+			Box::new(RuntimeCall::System(frame_system::Call::<Test>::remark { remark: vec![0] }));
+		let current_epoch = MockEpochInfo::epoch_index();
+
+		assert_ok!(Witnesser::witness_batch(
+			RuntimeOrigin::signed(ALISSA),
+			vec![increment.clone(), remark.clone()].try_into().unwrap(),
+			current_epoch
+		));
+		for call in [&increment, &remark] {
+			let call_hash = CallHash(frame_support::Hashable::blake2_256(&**call));
+			assert!(Votes::<Test>::contains_key(current_epoch, call_hash));
+		}
+		assert_eq!(pallet_dummy::Something::<Test>::get(), None);
+
+		// The second vote for each call reaches the threshold.
+		assert_ok!(Witnesser::witness_batch(
+			RuntimeOrigin::signed(BOBSON),
+			vec![increment, remark].try_into().unwrap(),
+			current_epoch
+		));
+		assert_eq!(pallet_dummy::Something::<Test>::get(), Some(0u32));
+
+		for witness in [ALISSA, BOBSON] {
+			assert_has_event::<Test>(RuntimeEvent::Witnesser(
+				crate::Event::WitnessBatchProcessed { witness, successful: 2, failed: 0 },
+			));
+		}
+	});
+}
+
+#[test]
+fn failed_votes_do_not_abort_the_rest_of_the_batch() {
+	new_test_ext().execute_with(|| {
+		let increment =
+			Box::new(RuntimeCall::Dummy(pallet_dummy::Call::<Test>::increment_value {}));
+		let remark =
+			Box::new(RuntimeCall::System(frame_system::Call::<Test>::remark { remark: vec![0] }));
+		let current_epoch = MockEpochInfo::epoch_index();
+
+		assert_ok!(Witnesser::witness_at_epoch(
+			RuntimeOrigin::signed(ALISSA),
+			increment.clone(),
+			current_epoch
+		));
+
+		// The vote for `increment` is a duplicate, but the vote for `remark` is still counted.
+		assert_eq!(
+			Witnesser::do_witness_batch(
+				ALISSA,
+				vec![increment, remark.clone()].try_into().unwrap(),
+				current_epoch
+			),
+			1
+		);
+		let remark_hash = CallHash(frame_support::Hashable::blake2_256(&*remark));
+		assert_eq!(
+			VoteMask::from_slice(&Votes::<Test>::get(current_epoch, remark_hash).unwrap())
+				.count_ones(),
+			1
+		);
+		assert_eq!(
+			frame_system::Pallet::<Test>::events().last().unwrap().event,
+			RuntimeEvent::Witnesser(crate::Event::WitnessBatchProcessed {
+				witness: ALISSA,
+				successful: 1,
+				failed: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn witness_batch_size_is_limited() {
+	new_test_ext().execute_with(|| {
+		let encoded_batch = |size: u32| {
+			let calls = (0..size)
+				.map(|i| {
+					Box::new(RuntimeCall::System(frame_system::Call::<Test>::remark {
+						remark: vec![i as u8],
+					}))
+				})
+				.collect::<Vec<_>>();
+			// Encoded as `Call::witness_batch`, bypassing the bound on the call's arguments.
+			(
+				<Witnesser as PalletInfoAccess>::index() as u8,
+				4u8,
+				calls,
+				MockEpochInfo::epoch_index(),
+			)
+				.encode()
+		};
+
+		assert!(RuntimeCall::decode(&mut &encoded_batch(MaxWitnessBatch::get())[..]).is_ok());
+		assert!(RuntimeCall::decode(&mut &encoded_batch(MaxWitnessBatch::get() + 1)[..]).is_err());
+	});
+}
@@ -85,4 +85,7 @@ pub mod common {
 	/// dispatched to the witnessing deadline. After the deadline is passed, any authorities failed
 	/// to witness the dispatched call are penalized.
 	pub const LATE_WITNESS_GRACE_PERIOD: BlockNumber = 10u32;
+
+	/// The maximum number of calls that can be submitted in a single witness batch.
+	pub const MAX_WITNESS_BATCH: u32 = 32u32;
 }
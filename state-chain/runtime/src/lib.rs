@@ -21,7 +21,7 @@ use crate::{
 	runtime_apis::{
 		runtime_decl_for_custom_runtime_api::CustomRuntimeApiV1, AuctionState, BoostPoolDepth,
 		BoostPoolDetails, BrokerInfo, DispatchErrorWithMessage, EventFilter,
-		FailingWitnessValidators, LiquidityProviderInfo, RuntimeApiPenalty,
+		FailingWitnessValidators, LiquidityProviderInfo, RuntimeApiEpochInfo, RuntimeApiPenalty,
 		SimulateSwapAdditionalOrder, SimulatedSwapInformation, ValidatorInfo,
 	},
 };
@@ -56,10 +56,11 @@ use pallet_cf_ingress_egress::{
 };
 use pallet_cf_pools::{
 	AskBidMap, AssetPair, OrderId, PoolLiquidity, PoolOrderbook, PoolPriceV1, PoolPriceV2,
-	UnidirectionalPoolDepth,
+	PoolSummary, UnidirectionalPoolDepth,
 };
 use pallet_cf_reputation::ExclusionList;
 use pallet_cf_swapping::{CcmSwapAmounts, SwapLegInfo};
+use pallet_cf_threshold_signature::KeygenParticipationStats;
 use pallet_cf_validator::SetSizeMaximisingAuctionResolver;
 use pallet_transaction_payment::{ConstFeeMultiplier, Multiplier};
 use scale_info::prelude::string::String;
@@ -431,6 +432,10 @@ impl pallet_cf_pools::Config for Runtime {
 	type LpBalance = LiquidityProvider;
 	type SwapQueueApi = Swapping;
 	type NetworkFee = NetworkFee;
+	type MinFlipBuyInterval = ConstU32<{ 10 * MINUTES }>;
+	type MaxFlipBuyInterval = ConstU32<{ 7 * DAYS }>;
+	type SwapVolumeBucketSize = ConstU32<HOURS>;
+	type MaxSwapVolumeHistory = ConstU32<24>;
 	type SafeMode = RuntimeSafeMode;
 	type WeightInfo = ();
 }
@@ -538,6 +543,10 @@ impl frame_system::Config for Runtime {
 		Funding,
 		AccountRoles,
 		Reputation,
+		EvmThresholdSigner,
+		PolkadotThresholdSigner,
+		BitcoinThresholdSigner,
+		SolanaThresholdSigner,
 	);
 	/// The data to be stored in an account.
 	type AccountData = ();
@@ -623,6 +632,7 @@ impl pallet_cf_witnesser::Config for Runtime {
 	type Offence = chainflip::Offence;
 	type OffenceReporter = Reputation;
 	type LateWitnessGracePeriod = ConstU32<LATE_WITNESS_GRACE_PERIOD>;
+	type MaxWitnessBatch = ConstU32<MAX_WITNESS_BATCH>;
 	type WeightInfo = pallet_cf_witnesser::weights::PalletWeight<Runtime>;
 }
 
@@ -662,6 +672,11 @@ impl pallet_cf_governance::Config for Runtime {
 	type RuntimeUpgrade = chainflip::RuntimeUpgradeManager;
 	type CompatibleCfeVersions = Environment;
 	type AuthoritiesCfeVersions = Validator;
+	// Networks launched with the root key as the only member bootstrap the rest of the set through
+	// governance before the minimum is enforced.
+	type MinimumMembers = ConstU32<3>;
+	type MaxActiveProposals = ConstU32<50>;
+	type MaxProposalsPerMember = ConstU32<10>;
 }
 
 impl pallet_cf_emissions::Config for Runtime {
@@ -1241,6 +1256,20 @@ impl_runtime_apis! {
 		fn cf_current_epoch_started_at() -> u32 {
 			Validator::current_epoch_started_at()
 		}
+		fn cf_authorities_at_epoch(epoch_index: EpochIndex) -> Vec<AccountId> {
+			Validator::authorities_at_epoch(epoch_index)
+		}
+		fn cf_current_authorities() -> Vec<(AccountId, u128)> {
+			let mut authorities = pallet_cf_validator::CurrentAuthorities::<Runtime>::get()
+				.into_iter()
+				.map(|account_id| {
+					let balance = pallet_cf_flip::Account::<Runtime>::get(&account_id).total();
+					(account_id, balance)
+				})
+				.collect::<Vec<_>>();
+			authorities.sort_by(|(_, a), (_, b)| b.cmp(a));
+			authorities
+		}
 		fn cf_authority_emission_per_block() -> u128 {
 			Emissions::current_authority_emission_per_block()
 		}
@@ -1265,6 +1294,15 @@ impl_runtime_apis! {
 		fn cf_account_flip_balance(account_id: &AccountId) -> u128 {
 			pallet_cf_flip::Account::<Runtime>::get(account_id).total()
 		}
+		fn cf_pending_redemption(account_id: AccountId) -> Option<u128> {
+			pallet_cf_funding::PendingRedemptions::<Runtime>::get(&account_id)
+				.map(|redemption| redemption.total)
+		}
+		fn cf_all_pending_redemptions() -> Vec<(AccountId, u128)> {
+			pallet_cf_funding::PendingRedemptions::<Runtime>::iter()
+				.map(|(account_id, redemption)| (account_id, redemption.total))
+				.collect()
+		}
 		fn cf_validator_info(account_id: &AccountId) -> ValidatorInfo {
 			let is_current_backup = pallet_cf_validator::Backups::<Runtime>::get().contains_key(account_id);
 			let key_holder_epochs = pallet_cf_validator::HistoricalActiveEpochs::<Runtime>::get(account_id);
@@ -1312,6 +1350,17 @@ impl_runtime_apis! {
 				})
 				.collect()
 		}
+		fn cf_offences(account_id: AccountId) -> Vec<(Offence, u32)> {
+			let mut offence_counts = pallet_cf_reputation::OffenceCounts::<Runtime>::iter_prefix(&account_id)
+				.collect::<Vec<_>>();
+			// Include every offence that carries a penalty, even if the account was never reported.
+			for offence in pallet_cf_reputation::Penalties::<Runtime>::iter_keys() {
+				if !offence_counts.iter().any(|(counted, _)| *counted == offence) {
+					offence_counts.push((offence, 0));
+				}
+			}
+			offence_counts
+		}
 		fn cf_generate_gov_key_call_hash(
 			call: Vec<u8>,
 		) -> GovCallHash {
@@ -1342,6 +1391,15 @@ impl_runtime_apis! {
 			}
 		}
 
+		fn cf_epoch_info() -> RuntimeApiEpochInfo {
+			RuntimeApiEpochInfo {
+				epoch_index: <Runtime as Chainflip>::EpochInfo::epoch_index(),
+				bond: <Runtime as Chainflip>::EpochInfo::bond(),
+				authority_count: <Runtime as Chainflip>::EpochInfo::current_authority_count(),
+				is_auction_phase: Validator::is_auction_phase(),
+			}
+		}
+
 		fn cf_pool_price(
 			from: Asset,
 			to: Asset,
@@ -1469,6 +1527,10 @@ impl_runtime_apis! {
 			LiquidityPools::pool_liquidity(base_asset, quote_asset).map_err(Into::into)
 		}
 
+		fn cf_all_pools() -> Vec<PoolSummary> {
+			LiquidityPools::all_pools()
+		}
+
 		fn cf_required_asset_ratio_for_range_order(
 			base_asset: Asset,
 			quote_asset: Asset,
@@ -1510,6 +1572,14 @@ impl_runtime_apis! {
 			Swapping::maximum_swap_amount(asset)
 		}
 
+		fn cf_swap_volume(asset: Asset) -> AssetAmount {
+			pallet_cf_pools::SwapVolume::<Runtime>::get(asset)
+		}
+
+		fn cf_last_flip_buy_block() -> Option<BlockNumber> {
+			pallet_cf_pools::LastFlipBuyBlock::<Runtime>::get()
+		}
+
 		fn cf_min_deposit_amount(asset: Asset) -> AssetAmount {
 			use pallet_cf_ingress_egress::MinimumDeposit;
 			match asset.into() {
@@ -1874,6 +1944,44 @@ impl_runtime_apis! {
 			}
 
 		}
+
+		fn cf_vault(chain: ForeignChain, epoch_index: EpochIndex) -> Option<(Vec<u8>, u64)> {
+			match chain {
+				ForeignChain::Ethereum => Some((
+					EvmThresholdSigner::keys(epoch_index)?.encode(),
+					EthereumVault::vault_start_block_numbers(epoch_index)?,
+				)),
+				ForeignChain::Polkadot => Some((
+					PolkadotThresholdSigner::keys(epoch_index)?.encode(),
+					PolkadotVault::vault_start_block_numbers(epoch_index)?.into(),
+				)),
+				ForeignChain::Bitcoin => Some((
+					BitcoinThresholdSigner::keys(epoch_index)?.encode(),
+					BitcoinVault::vault_start_block_numbers(epoch_index)?,
+				)),
+				ForeignChain::Arbitrum => Some((
+					EvmThresholdSigner::keys(epoch_index)?.encode(),
+					ArbitrumVault::vault_start_block_numbers(epoch_index)?,
+				)),
+				ForeignChain::Solana => Some((
+					SolanaThresholdSigner::keys(epoch_index)?.encode(),
+					SolanaVault::vault_start_block_numbers(epoch_index)?,
+				)),
+			}
+		}
+
+		fn cf_keygen_participation_stats(
+			chain: ForeignChain,
+			account_id: AccountId,
+		) -> KeygenParticipationStats {
+			match chain {
+				ForeignChain::Ethereum | ForeignChain::Arbitrum =>
+					EvmThresholdSigner::participation_stats(account_id),
+				ForeignChain::Polkadot => PolkadotThresholdSigner::participation_stats(account_id),
+				ForeignChain::Bitcoin => BitcoinThresholdSigner::participation_stats(account_id),
+				ForeignChain::Solana => SolanaThresholdSigner::participation_stats(account_id),
+			}
+		}
 	}
 
 	impl monitoring_apis::MonitoringRuntimeApi<Block> for Runtime {
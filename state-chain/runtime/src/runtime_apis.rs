@@ -18,9 +18,10 @@ use pallet_cf_governance::GovCallHash;
 pub use pallet_cf_ingress_egress::OwedAmount;
 use pallet_cf_pools::{
 	AskBidMap, PoolInfo, PoolLiquidity, PoolOrderbook, PoolOrders, PoolPriceV1, PoolPriceV2,
-	UnidirectionalPoolDepth,
+	PoolSummary, UnidirectionalPoolDepth,
 };
 use pallet_cf_swapping::SwapLegInfo;
+use pallet_cf_threshold_signature::KeygenParticipationStats;
 use pallet_cf_witnesser::CallHash;
 use scale_info::{prelude::string::String, TypeInfo};
 use serde::{Deserialize, Serialize};
@@ -106,6 +107,14 @@ pub struct RuntimeApiPenalty {
 	pub suspension_duration_blocks: u32,
 }
 
+#[derive(Encode, Decode, Eq, PartialEq, TypeInfo)]
+pub struct RuntimeApiEpochInfo {
+	pub epoch_index: EpochIndex,
+	pub bond: u128,
+	pub authority_count: u32,
+	pub is_auction_phase: bool,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, TypeInfo)]
 pub struct AuctionState {
 	pub blocks_per_epoch: u32,
@@ -185,17 +194,30 @@ decl_runtime_apis!(
 		fn cf_current_compatibility_version() -> SemVer;
 		fn cf_epoch_duration() -> u32;
 		fn cf_current_epoch_started_at() -> u32;
+		fn cf_authorities_at_epoch(epoch_index: EpochIndex) -> Vec<AccountId32>;
+		/// Returns the authorities of the current epoch with their total FLIP balance, sorted by
+		/// balance in descending order. During a rotation these are still the outgoing authorities.
+		fn cf_current_authorities() -> Vec<(AccountId32, u128)>;
 		fn cf_authority_emission_per_block() -> u128;
 		fn cf_backup_emission_per_block() -> u128;
 		/// Returns the flip supply in the form [total_issuance, offchain_funds]
 		fn cf_flip_supply() -> (u128, u128);
 		fn cf_accounts() -> Vec<(AccountId32, VanityName)>;
 		fn cf_account_flip_balance(account_id: &AccountId32) -> u128;
+		/// Returns the amount of the account's pending redemption, if it has one.
+		fn cf_pending_redemption(account_id: AccountId32) -> Option<u128>;
+		/// Returns the amount of every pending redemption, by account.
+		fn cf_all_pending_redemptions() -> Vec<(AccountId32, u128)>;
 		fn cf_validator_info(account_id: &AccountId32) -> ValidatorInfo;
 		fn cf_penalties() -> Vec<(Offence, RuntimeApiPenalty)>;
 		fn cf_suspensions() -> Vec<(Offence, Vec<(u32, AccountId32)>)>;
+		/// Returns the number of times the account has been reported for each offence.
+		fn cf_offences(account_id: AccountId32) -> Vec<(Offence, u32)>;
 		fn cf_generate_gov_key_call_hash(call: Vec<u8>) -> GovCallHash;
 		fn cf_auction_state() -> AuctionState;
+		/// Returns the current epoch index, bond and authority count, and whether an auction is
+		/// in progress.
+		fn cf_epoch_info() -> RuntimeApiEpochInfo;
 		fn cf_pool_price(from: Asset, to: Asset) -> Option<PoolPriceV1>;
 		fn cf_pool_price_v2(
 			base_asset: Asset,
@@ -220,6 +242,7 @@ decl_runtime_apis!(
 			base_asset: Asset,
 			quote_asset: Asset,
 		) -> Result<PoolLiquidity, DispatchErrorWithMessage>;
+		fn cf_all_pools() -> Vec<PoolSummary>;
 		fn cf_required_asset_ratio_for_range_order(
 			base_asset: Asset,
 			quote_asset: Asset,
@@ -243,6 +266,8 @@ decl_runtime_apis!(
 		) -> Result<PoolPairsMap<Amount>, DispatchErrorWithMessage>;
 
 		fn cf_max_swap_amount(asset: Asset) -> Option<AssetAmount>;
+		fn cf_swap_volume(asset: Asset) -> AssetAmount;
+		fn cf_last_flip_buy_block() -> Option<BlockNumber>;
 		fn cf_min_deposit_amount(asset: Asset) -> AssetAmount;
 		fn cf_egress_dust_limit(asset: Asset) -> AssetAmount;
 		fn cf_prewitness_swaps(
@@ -279,5 +304,12 @@ decl_runtime_apis!(
 		fn cf_get_events(filter: EventFilter) -> Vec<EventRecord<RuntimeEvent, Hash>>;
 		fn cf_boost_pools_depth() -> Vec<BoostPoolDepth>;
 		fn cf_boost_pool_details(asset: Asset) -> BTreeMap<u16, BoostPoolDetails>;
+		/// Returns the vault of the given chain for the given epoch in the form
+		/// [SCALE-encoded agg_key, active_from_block], if one exists.
+		fn cf_vault(chain: ForeignChain, epoch_index: EpochIndex) -> Option<(Vec<u8>, u64)>;
+		fn cf_keygen_participation_stats(
+			chain: ForeignChain,
+			account_id: AccountId32,
+		) -> KeygenParticipationStats;
 	}
 );
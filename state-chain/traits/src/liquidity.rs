@@ -1,7 +1,7 @@
 use cf_chains::{address::ForeignChainAddress, assets::any::AssetMap};
 use cf_primitives::{Asset, AssetAmount, Beneficiaries, ChannelId, SwapId};
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::pallet_prelude::{DispatchError, DispatchResult};
+use frame_support::pallet_prelude::{DispatchError, DispatchResult, Weight};
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 
@@ -107,6 +107,9 @@ pub trait SwappingApi {
 		to: Asset,
 		input_amount: AssetAmount,
 	) -> Result<AssetAmount, DispatchError>;
+
+	/// The weight of the storage bookkeeping done by a single call to `swap_single_leg`.
+	fn swap_single_leg_weight() -> Weight;
 }
 
 pub trait SwapQueueApi {
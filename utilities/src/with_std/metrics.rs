@@ -416,6 +416,18 @@ build_gauge_vec!(
 	"Gauge keeping track of the latest block number the engine reported to the state chain",
 	["chain"]
 );
+build_gauge_vec!(
+	WITNESS_PENDING_HEADERS,
+	"cfe_witness_pending_headers",
+	"Gauge keeping track of the number of external chain headers waiting for the chain tracking to pass them before being witnessed",
+	["chain"]
+);
+build_gauge_vec!(
+	WITNESS_READY_HEADERS,
+	"cfe_witness_ready_headers",
+	"Gauge keeping track of the number of external chain headers that are ready to be witnessed",
+	["chain"]
+);
 build_gauge_vec!(
 	AUTHORIZED_CEREMONIES,
 	"cfe_authorized_ceremonies",
@@ -469,6 +481,21 @@ build_histogram_vec_struct!(
 	["chain", "ceremony_type"],
 	(vec![32.0, 64.0, 72.0, 80.0, 88.0, 96.0, 104.0, 112.0, 120.0, 250.0, 300.0, 350.0])
 );
+build_counter_vec_struct!(
+	CEREMONY_OUTCOME,
+	CeremonyOutcome,
+	"cfe_ceremony_outcome",
+	"Count the outcomes of authorised ceremonies, labelled by success or a coarse failure reason",
+	["chain", "ceremony_type", "outcome"],
+	["chain", "ceremony_type"]
+);
+build_gauge_vec_struct!(
+	CEREMONY_PARTICIPANTS,
+	CeremonyParticipants,
+	"cfe_ceremony_participants",
+	"Gauge keeping track of the number of participants in the latest authorised ceremony",
+	["chain", "ceremony_type"]
+);
 build_gauge_vec_struct!(
 	CEREMONY_TIMEOUT_MISSING_MSG,
 	CeremonyTimeoutMissingMsg,
@@ -509,6 +536,8 @@ pub struct CeremonyMetrics {
 	pub processed_messages: CeremonyProcessedMsg,
 	pub bad_message: CeremonyBadMsg,
 	pub ceremony_duration: CeremonyDuration,
+	pub ceremony_outcome: CeremonyOutcome,
+	pub participants: CeremonyParticipants,
 	pub missing_messages: CeremonyTimeoutMissingMsg,
 	pub stage_duration: StageDuration,
 	pub stage_failing: StageFailing,
@@ -528,6 +557,14 @@ impl CeremonyMetrics {
 				&CEREMONY_DURATION,
 				[chain_name.clone(), ceremony_type.clone()],
 			),
+			ceremony_outcome: CeremonyOutcome::new(
+				&CEREMONY_OUTCOME,
+				[chain_name.clone(), ceremony_type.clone()],
+			),
+			participants: CeremonyParticipants::new(
+				&CEREMONY_PARTICIPANTS,
+				[chain_name.clone(), ceremony_type.clone()],
+			),
 			missing_messages: CeremonyTimeoutMissingMsg::new(
 				&CEREMONY_TIMEOUT_MISSING_MSG,
 				[chain_name.clone(), ceremony_type],
@@ -625,6 +662,8 @@ mod test {
 					let mut metrics = CeremonyMetrics::new("Chain1", "Keygen");
 					metrics.bad_message.inc(&["AA"]);
 					metrics.ceremony_duration.observe(Duration::new(999, 0));
+					metrics.ceremony_outcome.inc(&["success"]);
+					metrics.participants.set(3);
 					metrics.missing_messages.set(&["stage1",], 5);
 					metrics.processed_messages.inc();
 					metrics.processed_messages.inc();
@@ -659,6 +698,12 @@ cfe_ceremony_duration_count{ceremony_type="Keygen",chain="Chain1"} 1
 # HELP cfe_ceremony_msg Count all the processed messages for a given ceremony
 # TYPE cfe_ceremony_msg counter
 cfe_ceremony_msg{ceremony_type="Keygen",chain="Chain1"} 2
+# HELP cfe_ceremony_outcome Count the outcomes of authorised ceremonies, labelled by success or a coarse failure reason
+# TYPE cfe_ceremony_outcome counter
+cfe_ceremony_outcome{ceremony_type="Keygen",chain="Chain1",outcome="success"} 1
+# HELP cfe_ceremony_participants Gauge keeping track of the number of participants in the latest authorised ceremony
+# TYPE cfe_ceremony_participants gauge
+cfe_ceremony_participants{ceremony_type="Keygen",chain="Chain1"} 3
 # HELP cfe_ceremony_timeout_missing_msg Measure the number of missing messages when reaching timeout
 # TYPE cfe_ceremony_timeout_missing_msg gauge
 cfe_ceremony_timeout_missing_msg{ceremony_type="Keygen",chain="Chain1",stage="stage1"} 5